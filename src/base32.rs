@@ -0,0 +1,100 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ByteUtilsError;
+
+const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Encodes a byte slice as base32.
+///
+/// This function uses the RFC 4648 alphabet (`A-Z`, `2-7`) and pads the output
+/// with `=` to a multiple of eight characters.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to encode.
+///
+/// # Returns
+///
+/// A String containing the padded base32 representation of the input bytes.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base32::bytes_to_base32(b"foo"), "MZXW6===");
+/// assert_eq!(byteutils::base32::bytes_to_base32(&[]), "");
+/// ```
+pub fn bytes_to_base32(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    for chunk in bytes.chunks(5) {
+        let mut block = [0u8; 5];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let bits = block
+            .iter()
+            .fold(0u64, |acc, &byte| (acc << 8) | byte as u64);
+
+        let used = (chunk.len() * 8).div_ceil(5);
+        for i in 0..used {
+            let index = (bits >> (35 - i * 5)) & 0x1F;
+            out.push(ALPHABET[index as usize] as char);
+        }
+        for _ in used..8 {
+            out.push('=');
+        }
+    }
+    out
+}
+
+/// Decodes a base32 string into bytes.
+///
+/// The input must use the RFC 4648 alphabet and be padded with `=` to a multiple
+/// of eight characters. Lowercase letters are accepted as well as uppercase.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing padded base32 data.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The decoded bytes.
+/// - Err(ByteUtilsError): `InvalidBase32Char` for a character outside the alphabet
+///   (such as `0`, `1` or `8`), or `InvalidPadding` if the length or padding is incorrect.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base32::base32_to_bytes("MZXW6===").unwrap(), b"foo");
+/// assert_eq!(byteutils::base32::base32_to_bytes("mzxw6===").unwrap(), b"foo");
+/// assert!(byteutils::base32::base32_to_bytes("MZXW0===").is_err());
+/// ```
+pub fn base32_to_bytes(s: &str) -> Result<Vec<u8>, ByteUtilsError> {
+    if s.len() % 8 != 0 {
+        return Err(ByteUtilsError::InvalidPadding);
+    }
+
+    let data = s.trim_end_matches('=');
+    if !matches!(s.len() - data.len(), 0 | 1 | 3 | 4 | 6) {
+        return Err(ByteUtilsError::InvalidPadding);
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() * 5 / 8);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for (index, ch) in data.char_indices() {
+        let upper = ch.to_ascii_uppercase();
+        let value = ALPHABET
+            .iter()
+            .position(|&c| c as char == upper)
+            .ok_or(ByteUtilsError::InvalidBase32Char { index, ch })?;
+
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
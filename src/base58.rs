@@ -0,0 +1,104 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ByteUtilsError;
+
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes a byte slice as base58.
+///
+/// This function uses the Bitcoin alphabet, which leaves out `0`, `O`, `I` and
+/// `l` to avoid visually ambiguous characters. The input is treated as one
+/// big-endian number, and each leading zero byte is encoded as a leading `1`.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to encode.
+///
+/// # Returns
+///
+/// A String containing the base58 representation of the input bytes.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base58::bytes_to_base58(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+/// assert_eq!(byteutils::base58::bytes_to_base58(&[0, 0, 1]), "112");
+/// ```
+pub fn bytes_to_base58(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+
+    // Base58 digits of the number, least significant first.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for &byte in &bytes[zeros..] {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out = String::with_capacity(zeros + digits.len());
+    for _ in 0..zeros {
+        out.push('1');
+    }
+    for &digit in digits.iter().rev() {
+        out.push(ALPHABET[digit as usize] as char);
+    }
+    out
+}
+
+/// Decodes a base58 string into bytes.
+///
+/// The input must use the Bitcoin alphabet. Each leading `1` is decoded as a
+/// leading zero byte, so `bytes_to_base58` and this function round-trip exactly.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing base58 data.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The decoded bytes.
+/// - Err(ByteUtilsError): `InvalidBase58Char` for a character outside the alphabet.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base58::base58_to_bytes("2NEpo7TZRRrLZSi2U").unwrap(), b"Hello World!");
+/// assert!(byteutils::base58::base58_to_bytes("0OIl").is_err());
+/// ```
+pub fn base58_to_bytes(s: &str) -> Result<Vec<u8>, ByteUtilsError> {
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+
+    // Bytes of the number, least significant first.
+    let mut bytes: Vec<u8> = Vec::with_capacity(s.len() * 733 / 1000 + 1);
+    for (index, ch) in s.char_indices().skip(zeros) {
+        let value = ALPHABET
+            .iter()
+            .position(|&c| c as char == ch)
+            .ok_or(ByteUtilsError::InvalidBase58Char { index, ch })?;
+
+        let mut carry = value as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut out = Vec::with_capacity(zeros + bytes.len());
+    out.resize(zeros, 0);
+    out.extend(bytes.iter().rev());
+    Ok(out)
+}
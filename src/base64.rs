@@ -0,0 +1,170 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::ByteUtilsError;
+
+const STANDARD_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes a byte slice as standard base64.
+///
+/// This function uses the RFC 4648 standard alphabet (`A-Z`, `a-z`, `0-9`, `+`, `/`)
+/// and pads the output with `=` to a multiple of four characters.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to encode.
+///
+/// # Returns
+///
+/// A String containing the padded base64 representation of the input bytes.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base64::bytes_to_base64(b"Hello"), "SGVsbG8=");
+/// assert_eq!(byteutils::base64::bytes_to_base64(&[]), "");
+/// ```
+pub fn bytes_to_base64(bytes: &[u8]) -> String {
+    encode(bytes, STANDARD_ALPHABET, true)
+}
+
+/// Decodes a standard base64 string into bytes.
+///
+/// The input must use the RFC 4648 standard alphabet and be padded with `=` to a
+/// multiple of four characters. At most two padding characters are accepted, and
+/// only at the end of the input.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing padded base64 data.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The decoded bytes.
+/// - Err(ByteUtilsError): `InvalidBase64Char` for a character outside the alphabet,
+///   or `InvalidPadding` if the length or padding is incorrect.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base64::base64_to_bytes("SGVsbG8=").unwrap(), b"Hello");
+/// assert!(byteutils::base64::base64_to_bytes("SGVsbG8").is_err());
+/// ```
+pub fn base64_to_bytes(s: &str) -> Result<Vec<u8>, ByteUtilsError> {
+    if s.len() % 4 != 0 {
+        return Err(ByteUtilsError::InvalidPadding);
+    }
+
+    let data = s.trim_end_matches('=');
+    if s.len() - data.len() > 2 {
+        return Err(ByteUtilsError::InvalidPadding);
+    }
+
+    decode(data, STANDARD_ALPHABET)
+}
+
+/// Encodes a byte slice as URL-safe base64 without padding.
+///
+/// This function uses the RFC 4648 URL-safe alphabet, which replaces `+` and `/`
+/// with `-` and `_`, and omits the trailing `=` padding.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to encode.
+///
+/// # Returns
+///
+/// A String containing the unpadded URL-safe base64 representation of the input bytes.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base64::bytes_to_base64_url(&[0xFB, 0xFF]), "-_8");
+/// ```
+pub fn bytes_to_base64_url(bytes: &[u8]) -> String {
+    encode(bytes, URL_SAFE_ALPHABET, false)
+}
+
+/// Decodes an unpadded URL-safe base64 string into bytes.
+///
+/// The input must use the RFC 4648 URL-safe alphabet and must not contain `=`
+/// padding.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing unpadded URL-safe base64 data.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The decoded bytes.
+/// - Err(ByteUtilsError): `InvalidBase64Char` for a character outside the alphabet,
+///   or `InvalidPadding` if the length cannot be produced by the encoder.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::base64::base64_url_to_bytes("-_8").unwrap(), vec![0xFB, 0xFF]);
+/// ```
+pub fn base64_url_to_bytes(s: &str) -> Result<Vec<u8>, ByteUtilsError> {
+    decode(s, URL_SAFE_ALPHABET)
+}
+
+// Encodes bytes in groups of three into four alphabet characters.
+fn encode(bytes: &[u8], alphabet: &[u8; 64], pad: bool) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let sextets = [
+            b[0] >> 2,
+            ((b[0] & 0x03) << 4) | (b[1] >> 4),
+            ((b[1] & 0x0F) << 2) | (b[2] >> 6),
+            b[2] & 0x3F,
+        ];
+
+        let used = chunk.len() + 1;
+        for &sextet in &sextets[..used] {
+            out.push(alphabet[sextet as usize] as char);
+        }
+        if pad {
+            for _ in used..4 {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+// Decodes unpadded base64 data using the given alphabet.
+fn decode(data: &str, alphabet: &[u8; 64]) -> Result<Vec<u8>, ByteUtilsError> {
+    if data.len() % 4 == 1 {
+        return Err(ByteUtilsError::InvalidPadding);
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+    for (index, ch) in data.char_indices() {
+        let value = alphabet
+            .iter()
+            .position(|&c| c as char == ch)
+            .ok_or(ByteUtilsError::InvalidBase64Char { index, ch })?;
+
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,185 @@
+use alloc::vec::Vec;
+
+use crate::ByteUtilsError;
+
+/// XORs two byte slices of equal length element-wise.
+///
+/// # Arguments
+///
+/// * `a` - The first operand.
+/// * `b` - The second operand, which must be as long as `a`.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): A vector where each byte is `a[i] ^ b[i]`.
+/// - Err(ByteUtilsError): `InvalidLength` if `b` is not the same length as `a`.
+///
+/// # Example
+///
+/// ```rust
+/// let mixed = byteutils::bytes::xor_bytes(&[0b1100, 0xFF], &[0b1010, 0x0F]).unwrap();
+/// assert_eq!(mixed, vec![0b0110, 0xF0]);
+/// ```
+pub fn xor_bytes(a: &[u8], b: &[u8]) -> Result<Vec<u8>, ByteUtilsError> {
+    if a.len() != b.len() {
+        return Err(ByteUtilsError::InvalidLength {
+            expected: a.len(),
+            actual: b.len(),
+        });
+    }
+    Ok(a.iter().zip(b).map(|(x, y)| x ^ y).collect())
+}
+
+/// XORs data with a key that is repeated to cover the whole input.
+///
+/// This is the classic repeating-key XOR: byte `i` of the output is
+/// `data[i] ^ key[i % key.len()]`. Applying it twice with the same key returns
+/// the original data. It offers no real confidentiality and is meant for
+/// obfuscation and puzzle work only.
+///
+/// # Arguments
+///
+/// * `data` - The bytes to transform.
+/// * `key` - The key, cycled across `data`.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The transformed bytes, the same length as `data`.
+/// - Err(ByteUtilsError): `EmptyKey` if `key` is empty.
+///
+/// # Example
+///
+/// ```rust
+/// let hidden = byteutils::bytes::xor_bytes_repeating(b"hello", b"k").unwrap();
+/// let shown = byteutils::bytes::xor_bytes_repeating(&hidden, b"k").unwrap();
+/// assert_eq!(shown, b"hello");
+/// ```
+pub fn xor_bytes_repeating(data: &[u8], key: &[u8]) -> Result<Vec<u8>, ByteUtilsError> {
+    if key.is_empty() {
+        return Err(ByteUtilsError::EmptyKey);
+    }
+    Ok(data
+        .iter()
+        .zip(key.iter().cycle())
+        .map(|(x, k)| x ^ k)
+        .collect())
+}
+
+/// Concatenates several byte slices into a single vector.
+///
+/// The output is allocated once with the combined length of all slices.
+///
+/// # Arguments
+///
+/// * `slices` - The slices to join, in order.
+///
+/// # Returns
+///
+/// A Vec<u8> containing the bytes of every slice back to back.
+///
+/// # Example
+///
+/// ```rust
+/// let joined = byteutils::bytes::concat_bytes(&[b"ab", b"", b"cd"]);
+/// assert_eq!(joined, b"abcd");
+/// ```
+pub fn concat_bytes(slices: &[&[u8]]) -> Vec<u8> {
+    let len = slices.iter().map(|slice| slice.len()).sum();
+    let mut out = Vec::with_capacity(len);
+    for slice in slices {
+        out.extend_from_slice(slice);
+    }
+    out
+}
+
+/// Splits a byte slice on every occurrence of a delimiter byte.
+///
+/// This behaves like `str::split`: the delimiter is not included in the pieces,
+/// and leading, trailing or consecutive delimiters produce empty pieces. An
+/// empty input yields a single empty piece, and the number of pieces is always
+/// one more than the number of delimiters.
+///
+/// # Arguments
+///
+/// * `data` - The bytes to split.
+/// * `delimiter` - The byte to split on.
+///
+/// # Returns
+///
+/// A Vec<Vec<u8>> containing the pieces between delimiters.
+///
+/// # Example
+///
+/// ```rust
+/// let lines = byteutils::bytes::split_bytes_on(b"a\nbc\n", b'\n');
+/// assert_eq!(lines, vec![b"a".to_vec(), b"bc".to_vec(), vec![]]);
+/// ```
+pub fn split_bytes_on(data: &[u8], delimiter: u8) -> Vec<Vec<u8>> {
+    data.split(|&byte| byte == delimiter)
+        .map(<[u8]>::to_vec)
+        .collect()
+}
+
+/// Removes trailing `0x00` bytes from a slice.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to trim.
+///
+/// # Returns
+///
+/// A subslice of `bytes` without its trailing zero bytes. An all-zero input
+/// returns an empty slice.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::bytes::trim_trailing_zeros(&[0, 1, 0, 2, 0, 0]), &[0, 1, 0, 2]);
+/// ```
+pub fn trim_trailing_zeros(bytes: &[u8]) -> &[u8] {
+    let end = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    &bytes[..end]
+}
+
+/// Removes leading `0x00` bytes from a slice.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to trim.
+///
+/// # Returns
+///
+/// A subslice of `bytes` without its leading zero bytes. An all-zero input
+/// returns an empty slice.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::bytes::trim_leading_zeros(&[0, 0, 1, 0, 2, 0]), &[1, 0, 2, 0]);
+/// ```
+pub fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let start = bytes.iter().position(|&b| b != 0).unwrap_or(bytes.len());
+    &bytes[start..]
+}
+
+/// Removes `0x00` bytes from both ends of a slice.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to trim.
+///
+/// # Returns
+///
+/// A subslice of `bytes` without its leading and trailing zero bytes. Zero
+/// bytes between non-zero bytes are kept.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::bytes::trim_zeros(&[0, 1, 0, 2, 0]), &[1, 0, 2]);
+/// ```
+pub fn trim_zeros(bytes: &[u8]) -> &[u8] {
+    trim_leading_zeros(trim_trailing_zeros(bytes))
+}
@@ -0,0 +1,251 @@
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::ByteUtilsError;
+
+/// Computes the CRC32 checksum of a byte slice.
+///
+/// This is the standard IEEE CRC32 (reflected polynomial `0xEDB88320`) used by
+/// zlib, gzip, PNG and Ethernet. The lookup table is built once on first use.
+///
+/// # Arguments
+///
+/// * `bytes` - The data to checksum.
+///
+/// # Returns
+///
+/// The 32-bit CRC of the input.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::checksum::crc32(b"123456789"), 0xCBF4_3926);
+/// assert_eq!(format!("{:08x}", byteutils::checksum::crc32(b"")), "00000000");
+/// ```
+#[cfg(feature = "std")]
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let table = crc32_table();
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc = table[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Computes the Adler-32 checksum of a byte slice.
+///
+/// Adler-32 is the checksum used by zlib. It is faster but weaker than CRC32.
+/// The input is processed in blocks of 5552 bytes, the largest block whose sums
+/// cannot overflow a `u32` before being reduced modulo 65521.
+///
+/// # Arguments
+///
+/// * `bytes` - The data to checksum.
+///
+/// # Returns
+///
+/// The 32-bit Adler-32 checksum of the input.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::checksum::adler32(b"Wikipedia"), 0x11E6_0398);
+/// assert_eq!(byteutils::checksum::adler32(b""), 1);
+/// ```
+pub fn adler32(bytes: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    const BLOCK_LEN: usize = 5552;
+
+    let mut a = 1u32;
+    let mut b = 0u32;
+    for block in bytes.chunks(BLOCK_LEN) {
+        for &byte in block {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+/// Appends the big-endian CRC32 of a payload to form a checksummed frame.
+///
+/// The returned frame is the payload followed by four bytes holding the CRC32
+/// (IEEE polynomial) of the payload, most significant byte first.
+///
+/// # Arguments
+///
+/// * `payload` - The data to frame.
+///
+/// # Returns
+///
+/// A Vec<u8> containing the payload followed by its 4-byte CRC32.
+///
+/// # Example
+///
+/// ```rust
+/// let frame = byteutils::checksum::frame_with_crc32(b"123456789");
+/// assert_eq!(&frame[9..], &[0xCB, 0xF4, 0x39, 0x26]);
+/// ```
+#[cfg(feature = "std")]
+pub fn frame_with_crc32(payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(payload.len() + 4);
+    frame.extend_from_slice(payload);
+    frame.extend_from_slice(&crc32(payload).to_be_bytes());
+    frame
+}
+
+/// Verifies a frame produced by `frame_with_crc32` and returns its payload.
+///
+/// The last four bytes of `frame` are read as a big-endian CRC32 and compared
+/// against the CRC32 of the preceding bytes.
+///
+/// # Arguments
+///
+/// * `frame` - The framed data, including the trailing 4-byte CRC32.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(&[u8]): The payload slice, without the trailing checksum.
+/// - Err(ByteUtilsError): `FrameTooShort` if the frame has fewer than four bytes,
+///   or `ChecksumMismatch` if the stored CRC32 does not match the payload.
+///
+/// # Example
+///
+/// ```rust
+/// let frame = byteutils::checksum::frame_with_crc32(b"hello");
+/// assert_eq!(byteutils::checksum::verify_crc32_frame(&frame).unwrap(), b"hello");
+/// ```
+#[cfg(feature = "std")]
+pub fn verify_crc32_frame(frame: &[u8]) -> Result<&[u8], ByteUtilsError> {
+    if frame.len() < 4 {
+        return Err(ByteUtilsError::FrameTooShort);
+    }
+
+    let (payload, trailer) = frame.split_at(frame.len() - 4);
+    let expected = u32::from_be_bytes([trailer[0], trailer[1], trailer[2], trailer[3]]);
+    let actual = crc32(payload);
+    if expected != actual {
+        return Err(ByteUtilsError::ChecksumMismatch { expected, actual });
+    }
+    Ok(payload)
+}
+
+/// Checks whether a numeric string passes the Luhn mod-10 checksum.
+///
+/// Spaces and dashes are ignored, so formatted card numbers such as
+/// `"4539 1488 0343 6467"` can be passed directly. The last digit is treated as
+/// the check digit. Any other non-digit character, or fewer than two digits,
+/// makes the input invalid.
+///
+/// # Arguments
+///
+/// * `digits` - The numeric string to validate, including its check digit.
+///
+/// # Returns
+///
+/// `true` if the input contains only digits and separators and its checksum is valid,
+/// `false` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// assert!(byteutils::checksum::luhn_valid("4539 1488 0343 6467"));
+/// assert!(!byteutils::checksum::luhn_valid("4539 1488 0343 6468"));
+/// ```
+pub fn luhn_valid(digits: &str) -> bool {
+    match luhn_checksum(digits, false) {
+        Ok((count, sum)) => count >= 2 && sum % 10 == 0,
+        Err(_) => false,
+    }
+}
+
+/// Computes the Luhn check digit for a numeric payload.
+///
+/// Spaces and dashes are ignored. Appending the returned digit to the payload
+/// produces a string accepted by `luhn_valid`.
+///
+/// # Arguments
+///
+/// * `digits` - The numeric payload, without a check digit.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(u8): The check digit, in the range `0..=9`.
+/// - Err(ByteUtilsError): `InvalidDigit` if the payload contains a character other
+///   than an ASCII digit, space or dash, or `EmptyInput` if it contains no digits.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::checksum::luhn_check_digit("7992739871").unwrap(), 3);
+/// assert!(byteutils::checksum::luhn_check_digit("12a4").is_err());
+/// ```
+pub fn luhn_check_digit(digits: &str) -> Result<u8, ByteUtilsError> {
+    let (count, sum) = luhn_checksum(digits, true)?;
+    if count == 0 {
+        return Err(ByteUtilsError::EmptyInput);
+    }
+    Ok(((10 - sum % 10) % 10) as u8)
+}
+
+// Yields the digits of a Luhn input, skipping spaces and dashes.
+fn luhn_digits(s: &str) -> impl DoubleEndedIterator<Item = Result<u32, ByteUtilsError>> + '_ {
+    s.char_indices()
+        .filter(|&(_, ch)| ch != ' ' && ch != '-')
+        .map(|(index, ch)| {
+            ch.to_digit(10)
+                .ok_or(ByteUtilsError::InvalidDigit { index, ch })
+        })
+}
+
+// Validates a Luhn input and returns its digit count and weighted sum. Digits are
+// summed right to left, doubling every second one. `double_first` selects whether
+// the rightmost digit is doubled, which is the case for a payload that does not
+// yet carry its check digit.
+fn luhn_checksum(s: &str, double_first: bool) -> Result<(usize, u32), ByteUtilsError> {
+    // Validate left to right first so an error names the first invalid character.
+    let count = luhn_digits(s).try_fold(0, |count, digit| digit.map(|_| count + 1))?;
+    let sum = luhn_digits(s)
+        .rev()
+        .flatten()
+        .enumerate()
+        .map(|(i, digit)| {
+            if (i % 2 == 0) == double_first {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    Ok((count, sum))
+}
+
+// Lazily builds the 256-entry CRC32 lookup table.
+#[cfg(feature = "std")]
+fn crc32_table() -> &'static [u32; 256] {
+    static TABLE: OnceLock<[u32; 256]> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut table = [0u32; 256];
+        for (n, entry) in table.iter_mut().enumerate() {
+            let mut c = n as u32;
+            for _ in 0..8 {
+                c = if c & 1 != 0 {
+                    0xEDB8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+            }
+            *entry = c;
+        }
+        table
+    })
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+// Shortest run of matching bytes worth encoding as a copy instead of an insert.
+const MIN_MATCH: usize = 4;
+
+// Maximum number of earlier positions checked for each block when searching for a match.
+const MAX_CANDIDATES: usize = 16;
+
+/// A single operation of a byte delta produced by `byte_delta`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeltaOp {
+    /// Copy `len` bytes from the old data, starting at offset `start`.
+    Copy {
+        /// The offset of the first copied byte in the old data.
+        start: usize,
+        /// The number of bytes to copy.
+        len: usize,
+    },
+    /// Insert the given bytes literally.
+    Insert(Vec<u8>),
+}
+
+/// Computes a delta that turns `old` into `new` as copy and insert operations.
+///
+/// The delta is built greedily: `old` is indexed by 4-byte blocks, and at each
+/// position of `new` the longest match found through the index is emitted as a
+/// `Copy`. Bytes without a match of at least 4 bytes are collected into `Insert`
+/// operations. The result is not guaranteed to be minimal, but applying it with
+/// `apply_delta` always reproduces `new` exactly.
+///
+/// # Arguments
+///
+/// * `old` - The original data.
+/// * `new` - The updated data.
+///
+/// # Returns
+///
+/// A Vec<DeltaOp> describing how to build `new` from `old`.
+///
+/// # Example
+///
+/// ```rust
+/// use byteutils::delta::{apply_delta, byte_delta, DeltaOp};
+///
+/// let old = b"hello world, hello rust";
+/// let new = b"hello rust, hello world!";
+/// let ops = byte_delta(old, new);
+/// assert_eq!(apply_delta(old, &ops), new);
+/// assert!(ops.iter().any(|op| matches!(op, DeltaOp::Copy { .. })));
+/// ```
+pub fn byte_delta(old: &[u8], new: &[u8]) -> Vec<DeltaOp> {
+    let mut index: HashMap<&[u8], Vec<usize>> = HashMap::new();
+    if old.len() >= MIN_MATCH {
+        for start in 0..=old.len() - MIN_MATCH {
+            index
+                .entry(&old[start..start + MIN_MATCH])
+                .or_default()
+                .push(start);
+        }
+    }
+
+    let mut ops = Vec::new();
+    let mut pending = Vec::new();
+    let mut pos = 0;
+    while pos < new.len() {
+        let best = new
+            .get(pos..pos + MIN_MATCH)
+            .and_then(|block| index.get(block))
+            .and_then(|candidates| {
+                candidates
+                    .iter()
+                    .take(MAX_CANDIDATES)
+                    .map(|&start| (start, common_prefix_len(&old[start..], &new[pos..])))
+                    .max_by_key(|&(_, len)| len)
+            });
+
+        match best {
+            Some((start, len)) => {
+                if !pending.is_empty() {
+                    ops.push(DeltaOp::Insert(std::mem::take(&mut pending)));
+                }
+                push_copy(&mut ops, start, len);
+                pos += len;
+            }
+            None => {
+                pending.push(new[pos]);
+                pos += 1;
+            }
+        }
+    }
+
+    if !pending.is_empty() {
+        ops.push(DeltaOp::Insert(pending));
+    }
+    ops
+}
+
+/// Rebuilds the new data from the old data and a delta produced by `byte_delta`.
+///
+/// # Arguments
+///
+/// * `old` - The original data the delta was computed against.
+/// * `ops` - The delta operations.
+///
+/// # Returns
+///
+/// A Vec<u8> containing the reconstructed data.
+///
+/// # Panics
+///
+/// This function will panic if a `Copy` operation refers to bytes outside of `old`.
+///
+/// # Example
+///
+/// ```rust
+/// use byteutils::delta::{apply_delta, DeltaOp};
+///
+/// let ops = vec![DeltaOp::Copy { start: 6, len: 5 }, DeltaOp::Insert(b"!".to_vec())];
+/// assert_eq!(apply_delta(b"hello world", &ops), b"world!");
+/// ```
+pub fn apply_delta(old: &[u8], ops: &[DeltaOp]) -> Vec<u8> {
+    let mut result = Vec::new();
+    for op in ops {
+        match op {
+            DeltaOp::Copy { start, len } => result.extend_from_slice(&old[*start..*start + *len]),
+            DeltaOp::Insert(bytes) => result.extend_from_slice(bytes),
+        }
+    }
+    result
+}
+
+// Returns the number of leading bytes `a` and `b` have in common.
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+// Appends a copy operation, merging it into the previous one when they are contiguous.
+fn push_copy(ops: &mut Vec<DeltaOp>, start: usize, len: usize) {
+    if let Some(DeltaOp::Copy {
+        start: prev_start,
+        len: prev_len,
+    }) = ops.last_mut()
+    {
+        if *prev_start + *prev_len == start {
+            *prev_len += len;
+            return;
+        }
+    }
+    ops.push(DeltaOp::Copy { start, len });
+}
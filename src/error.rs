@@ -0,0 +1,271 @@
+use alloc::string::String;
+use core::fmt;
+
+/// The error type returned by the fallible conversions in this crate.
+///
+/// Each variant describes a distinct failure reason so callers can match on
+/// it instead of comparing error messages.
+///
+/// # Example
+///
+/// ```rust
+/// use byteutils::ByteUtilsError;
+///
+/// match byteutils::hex_to_bytes("0g") {
+///     Err(ByteUtilsError::InvalidHexChar { index, ch }) => {
+///         assert_eq!(index, 1);
+///         assert_eq!(ch, 'g');
+///     }
+///     other => panic!("unexpected result: {:?}", other),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ByteUtilsError {
+    /// The hex input does not contain an even number of digits.
+    OddLength,
+    /// The hex input contains a character that is not a hexadecimal digit.
+    InvalidHexChar {
+        /// The byte offset of the character in the input string.
+        index: usize,
+        /// The offending character.
+        ch: char,
+    },
+    /// The bytes are not a valid UTF-8 sequence.
+    InvalidUtf8 {
+        /// The length of the longest valid UTF-8 prefix of the input.
+        valid_up_to: usize,
+        /// The length of the invalid sequence after the valid prefix, or `None`
+        /// if the input ends partway through a multi-byte character.
+        error_len: Option<usize>,
+    },
+    /// The binary input length is not a multiple of 8.
+    InvalidBinaryLength {
+        /// The length of the input string.
+        len: usize,
+    },
+    /// The binary input contains a character other than `0` or `1`.
+    InvalidBinaryChar {
+        /// The byte offset of the character in the input string.
+        index: usize,
+        /// The offending character.
+        ch: char,
+    },
+    /// A bit position is outside the range `0..=7` of a byte.
+    InvalidBitPosition {
+        /// The offending bit position.
+        position: u8,
+    },
+    /// The base64 input contains a character outside the expected alphabet.
+    InvalidBase64Char {
+        /// The byte offset of the character in the input string.
+        index: usize,
+        /// The offending character.
+        ch: char,
+    },
+    /// The base32 input contains a character outside the expected alphabet.
+    InvalidBase32Char {
+        /// The byte offset of the character in the input string.
+        index: usize,
+        /// The offending character.
+        ch: char,
+    },
+    /// The base58 input contains a character outside the expected alphabet.
+    InvalidBase58Char {
+        /// The byte offset of the character in the input string.
+        index: usize,
+        /// The offending character.
+        ch: char,
+    },
+    /// A `%` in percent-encoded input is not followed by two hex digits.
+    InvalidPercentEscape {
+        /// The byte offset of the `%` in the input string.
+        index: usize,
+    },
+    /// The encoded input has a length or padding the encoder could not produce.
+    InvalidPadding,
+    /// A checksummed frame is too short to contain its checksum.
+    FrameTooShort,
+    /// The checksum stored in a frame does not match its payload.
+    ChecksumMismatch {
+        /// The checksum stored in the frame.
+        expected: u32,
+        /// The checksum computed from the payload.
+        actual: u32,
+    },
+    /// A numeric string contains a character that is not a decimal digit.
+    InvalidDigit {
+        /// The byte offset of the character in the input string.
+        index: usize,
+        /// The offending character.
+        ch: char,
+    },
+    /// A chunk size or step of 0 was given.
+    InvalidChunkSize {
+        /// The requested chunk size.
+        size: usize,
+        /// The requested step between chunks.
+        step: usize,
+    },
+    /// A byte slice does not have the length required by a fixed-size conversion.
+    InvalidLength {
+        /// The number of bytes the conversion requires.
+        expected: usize,
+        /// The number of bytes that were given.
+        actual: usize,
+    },
+    /// An interleaved buffer cannot be split evenly into the requested channels.
+    InvalidChannelLayout {
+        /// The length of the interleaved buffer.
+        len: usize,
+        /// The requested number of channels.
+        channels: usize,
+    },
+    /// A channel buffer does not have the same length as the first channel.
+    ChannelLengthMismatch {
+        /// The index of the offending channel.
+        channel: usize,
+        /// The length of the first channel.
+        expected: usize,
+        /// The length of the offending channel.
+        actual: usize,
+    },
+    /// An XOR key of length 0 was given.
+    EmptyKey,
+    /// A word matcher was created from an empty word list.
+    EmptyWordList,
+    /// A digit string contained no digits.
+    EmptyInput,
+    /// A search pattern could not be compiled.
+    InvalidPattern {
+        /// The error reported by the pattern compiler.
+        message: String,
+    },
+}
+
+impl fmt::Display for ByteUtilsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ByteUtilsError::OddLength => {
+                write!(f, "Hex string must have an even number of characters")
+            }
+            ByteUtilsError::InvalidHexChar { index, ch } => {
+                write!(
+                    f,
+                    "Invalid hex string: invalid character {:?} at index {}",
+                    ch, index
+                )
+            }
+            ByteUtilsError::InvalidUtf8 { valid_up_to, .. } => {
+                write!(
+                    f,
+                    "Invalid UTF-8 sequence: valid up to index {}",
+                    valid_up_to
+                )
+            }
+            ByteUtilsError::InvalidBinaryLength { len } => {
+                write!(
+                    f,
+                    "Binary string length must be a multiple of 8, got {}",
+                    len
+                )
+            }
+            ByteUtilsError::InvalidBinaryChar { index, ch } => {
+                write!(
+                    f,
+                    "Invalid binary string: invalid character {:?} at index {}",
+                    ch, index
+                )
+            }
+            ByteUtilsError::InvalidBitPosition { position } => {
+                write!(f, "Bit position {} is out of range 0-7", position)
+            }
+            ByteUtilsError::InvalidBase64Char { index, ch } => {
+                write!(
+                    f,
+                    "Invalid base64 string: invalid character {:?} at index {}",
+                    ch, index
+                )
+            }
+            ByteUtilsError::InvalidBase32Char { index, ch } => {
+                write!(
+                    f,
+                    "Invalid base32 string: invalid character {:?} at index {}",
+                    ch, index
+                )
+            }
+            ByteUtilsError::InvalidBase58Char { index, ch } => {
+                write!(
+                    f,
+                    "Invalid base58 string: invalid character {:?} at index {}",
+                    ch, index
+                )
+            }
+            ByteUtilsError::InvalidPercentEscape { index } => {
+                write!(f, "Invalid percent escape at index {}", index)
+            }
+            ByteUtilsError::InvalidPadding => {
+                write!(f, "Encoded string has an invalid length or padding")
+            }
+            ByteUtilsError::FrameTooShort => {
+                write!(f, "Frame is too short to contain a checksum")
+            }
+            ByteUtilsError::ChecksumMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Checksum mismatch: expected {:08x}, computed {:08x}",
+                    expected, actual
+                )
+            }
+            ByteUtilsError::InvalidDigit { index, ch } => {
+                write!(
+                    f,
+                    "Invalid numeric string: invalid character {:?} at index {}",
+                    ch, index
+                )
+            }
+            ByteUtilsError::InvalidChunkSize { size, step } => {
+                write!(
+                    f,
+                    "Chunk size and step must be greater than 0 (size {}, step {})",
+                    size, step
+                )
+            }
+            ByteUtilsError::InvalidLength { expected, actual } => {
+                write!(f, "Expected {} bytes, got {}", expected, actual)
+            }
+            ByteUtilsError::InvalidChannelLayout { len, channels } => {
+                write!(
+                    f,
+                    "Cannot split {} bytes into {} equal channels",
+                    len, channels
+                )
+            }
+            ByteUtilsError::ChannelLengthMismatch {
+                channel,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Channel {} has {} bytes, expected {}",
+                    channel, actual, expected
+                )
+            }
+            ByteUtilsError::EmptyKey => {
+                write!(f, "Key must contain at least one byte")
+            }
+            ByteUtilsError::EmptyWordList => {
+                write!(f, "Word list must contain at least one word")
+            }
+            ByteUtilsError::EmptyInput => {
+                write!(f, "Input must contain at least one digit")
+            }
+            ByteUtilsError::InvalidPattern { message } => {
+                write!(f, "Invalid search pattern: {}", message)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ByteUtilsError {}
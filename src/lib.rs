@@ -1,11 +1,37 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 #[rustfmt::skip]
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 #[path = "./test.rs"]
 mod test;
 
+pub mod base32;
+pub mod base58;
+pub mod base64;
+pub mod bytes;
+pub mod checksum;
+#[cfg(feature = "std")]
+pub mod delta;
+mod error;
+pub mod num;
+pub mod percent;
+pub mod stream;
+#[cfg(feature = "std")]
 pub mod string;
 pub mod vec;
 
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+pub use error::ByteUtilsError;
+
+// Hex digits indexed by nibble value, shared by all hex encoders in the crate.
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
 /// Converts a byte slice to its hexadecimal string representation.
 ///
 /// This function takes a slice of bytes and returns a String containing the
@@ -28,19 +54,153 @@ pub mod vec;
 /// ````
 ///
 pub fn bytes_to_hex(bytes: &[u8]) -> String {
+    encode_hex(bytes, false)
+}
+
+/// Converts a byte slice to its uppercase hexadecimal string representation.
+///
+/// This function behaves exactly like `bytes_to_hex`, except that the
+/// hexadecimal digits `a-f` are emitted in uppercase, as is conventional for
+/// MAC addresses, certificate fingerprints and similar displays.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to convert to hexadecimal.
+///
+/// # Returns
+///
+/// A String containing the uppercase hexadecimal representation of the input bytes.
+///
+/// # Example
+/// ```rust
+/// let bytes = &[0xDE, 0xAD, 0xBE, 0xEF];
+/// assert_eq!(byteutils::bytes_to_hex_upper(bytes), "DEADBEEF");
+/// ```
+///
+pub fn bytes_to_hex_upper(bytes: &[u8]) -> String {
+    encode_hex(bytes, true)
+}
+
+// Shared hex encoding loop for `bytes_to_hex` and `bytes_to_hex_upper`.
+fn encode_hex(bytes: &[u8], upper: bool) -> String {
+    let digits = if upper { HEX_UPPER } else { HEX_LOWER };
     let mut hex = String::with_capacity(bytes.len() * 2);
     for &byte in bytes {
-        hex.push_str(&format!("{:02x}", byte));
+        hex.push(digits[(byte >> 4) as usize] as char);
+        hex.push(digits[(byte & 0x0F) as usize] as char);
     }
     hex
 }
 
+/// Converts a byte slice to a lowercase hex string with separators between groups.
+///
+/// The separator is inserted after every `group_size` bytes (not hex digits), so
+/// `group_size = 1` with `":"` gives MAC-style output and `group_size = 2` with
+/// `" "` groups the output into 16-bit words. No separator is added before the
+/// first or after the last group. A `group_size` of 0 disables grouping, making
+/// the output identical to `bytes_to_hex`.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to convert to hexadecimal.
+/// * `group_size` - The number of bytes in each group.
+/// * `separator` - The separator to insert between groups.
+///
+/// # Returns
+///
+/// A String containing the grouped hexadecimal representation of the input bytes.
+///
+/// # Example
+/// ```rust
+/// let bytes = &[0xDE, 0xAD, 0xBE, 0xEF];
+/// assert_eq!(byteutils::bytes_to_hex_grouped(bytes, 1, ":"), "de:ad:be:ef");
+/// assert_eq!(byteutils::bytes_to_hex_grouped(bytes, 2, " "), "dead beef");
+/// ```
+///
+pub fn bytes_to_hex_grouped(bytes: &[u8], group_size: usize, separator: &str) -> String {
+    if group_size == 0 {
+        return bytes_to_hex(bytes);
+    }
+
+    bytes
+        .chunks(group_size)
+        .map(bytes_to_hex)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Writes the hexadecimal representation of a byte slice into a writer.
+///
+/// This function produces exactly the same lowercase output as `bytes_to_hex`,
+/// but writes it straight into `out` instead of building an intermediate String.
+/// Wrapping the destination in a `BufWriter` is recommended for large inputs.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to convert to hexadecimal.
+/// * `out` - The writer that receives the hexadecimal characters.
+///
+/// # Returns
+///
+/// An `io::Result` that is `Err` if writing to `out` fails.
+///
+/// # Example
+/// ```rust
+/// let mut out = Vec::new();
+/// byteutils::write_hex(&[0xDE, 0xAD, 0xBE, 0xEF], &mut out).unwrap();
+/// assert_eq!(out, b"deadbeef");
+/// ```
+///
+#[cfg(feature = "std")]
+pub fn write_hex<W: std::io::Write>(bytes: &[u8], out: &mut W) -> std::io::Result<()> {
+    let mut buf = [0u8; 512];
+    for chunk in bytes.chunks(buf.len() / 2) {
+        for (i, &byte) in chunk.iter().enumerate() {
+            buf[i * 2] = HEX_LOWER[(byte >> 4) as usize];
+            buf[i * 2 + 1] = HEX_LOWER[(byte & 0x0F) as usize];
+        }
+        out.write_all(&buf[..chunk.len() * 2])?;
+    }
+    Ok(())
+}
+
+/// Lazily yields the hexadecimal digits of a byte slice.
+///
+/// This function produces exactly the same lowercase characters as
+/// `bytes_to_hex`, two per input byte, without allocating. The iterator can be
+/// collected into a String, written into a fixed buffer or chained with other
+/// adapters.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to convert to hexadecimal.
+///
+/// # Returns
+///
+/// An iterator over the hexadecimal characters of the input.
+///
+/// # Example
+/// ```rust
+/// let hex: String = byteutils::hex_chars(&[0xDE, 0xAD]).collect();
+/// assert_eq!(hex, "dead");
+/// assert_eq!(byteutils::hex_chars(&[0x0F]).last(), Some('f'));
+/// ```
+///
+pub fn hex_chars(bytes: &[u8]) -> impl Iterator<Item = char> + '_ {
+    bytes.iter().flat_map(|&byte| {
+        [
+            HEX_LOWER[(byte >> 4) as usize] as char,
+            HEX_LOWER[(byte & 0x0F) as usize] as char,
+        ]
+    })
+}
+
 /// Converts a hexadecimal string to its byte representation.
 ///
 /// This function takes a string slice containing a hexadecimal representation
-/// and returns a Vec<u8> containing the corresponding bytes. The input string
-/// must have an even number of characters and contain only valid hexadecimal
-/// digits (0-9, a-f, A-F).
+/// and returns a Vec<u8> containing the corresponding bytes. A single leading
+/// `0x` or `0X` prefix is ignored. The remaining string must have an even number
+/// of characters and contain only valid hexadecimal digits (0-9, a-f, A-F).
 ///
 /// # Arguments
 ///
@@ -50,28 +210,184 @@ pub fn bytes_to_hex(bytes: &[u8]) -> String {
 ///
 /// A Result containing either:
 /// - Ok(Vec<u8>): The byte representation of the input hexadecimal string.
-/// - Err(String): An error message if the input is invalid.
+/// - Err(ByteUtilsError): `OddLength` or `InvalidHexChar` if the input is invalid.
 ///
 /// # Example
 /// ```rust
 /// let bytes = byteutils::hex_to_bytes("0fff0080").unwrap();
 /// assert_eq!(byteutils::hex_to_bytes("0fff0080").unwrap(), vec![15, 255, 0, 128]);
+/// assert_eq!(byteutils::hex_to_bytes("0xdeadbeef").unwrap(), vec![0xDE, 0xAD, 0xBE, 0xEF]);
 /// ````
 ///
-pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, String> {
-    if hex.len() % 2 != 0 {
-        return Err("Hex string must have an even number of characters".to_string());
+pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, ByteUtilsError> {
+    let mut bytes = Vec::new();
+    hex_to_bytes_into(hex, &mut bytes)?;
+    Ok(bytes)
+}
+
+/// Decodes a hexadecimal string and appends the bytes to an existing buffer.
+///
+/// This function accepts the same input as `hex_to_bytes`, but appends the decoded
+/// bytes to `out` instead of allocating a new Vec, so one buffer can be reused
+/// across many decodes. Decoding is all-or-nothing: if the input is invalid,
+/// `out` is left exactly as it was before the call.
+///
+/// # Arguments
+///
+/// * `hex` - A string slice containing the hexadecimal representation to convert.
+/// * `out` - The buffer that receives the decoded bytes.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(()): All bytes were decoded and appended to `out`.
+/// - Err(ByteUtilsError): `OddLength` or `InvalidHexChar` if the input is invalid.
+///   Nothing is appended to `out` in this case.
+///
+/// # Example
+/// ```rust
+/// let mut buf = vec![0x01];
+/// byteutils::hex_to_bytes_into("dead", &mut buf).unwrap();
+/// assert_eq!(buf, vec![0x01, 0xDE, 0xAD]);
+///
+/// assert!(byteutils::hex_to_bytes_into("beXX", &mut buf).is_err());
+/// assert_eq!(buf, vec![0x01, 0xDE, 0xAD]);
+/// ```
+///
+pub fn hex_to_bytes_into(hex: &str, out: &mut Vec<u8>) -> Result<(), ByteUtilsError> {
+    let digits = hex
+        .strip_prefix("0x")
+        .or_else(|| hex.strip_prefix("0X"))
+        .unwrap_or(hex);
+
+    if digits.len() % 2 != 0 {
+        return Err(ByteUtilsError::OddLength);
     }
 
-    let mut bytes = Vec::with_capacity(hex.len() / 2);
-    for chunk in hex.as_bytes().chunks(2) {
-        let byte = u8::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16)
-            .map_err(|e| format!("Invalid hex string: {}", e))?;
-        bytes.push(byte);
+    let offset = hex.len() - digits.len();
+    out.reserve(digits.len() / 2);
+    decode_hex_digits(digits.char_indices().map(|(i, ch)| (offset + i, ch)), out)
+}
+
+/// Converts a formatted hexadecimal string to its byte representation.
+///
+/// This function is a lenient variant of `hex_to_bytes` for hex dumps such as
+/// `"de ad be ef"` or `"de:ad:be:ef"`. All ASCII whitespace and any of the given
+/// separator characters are removed before decoding. The remaining digits must
+/// form an even number of valid hexadecimal characters.
+///
+/// # Arguments
+///
+/// * `hex` - A string slice containing the formatted hexadecimal representation.
+/// * `separators` - Additional characters to ignore between hex digits.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The byte representation of the input hexadecimal string.
+/// - Err(ByteUtilsError): `OddLength` or `InvalidHexChar` if the input is invalid.
+///
+/// # Example
+/// ```rust
+/// let bytes = byteutils::hex_to_bytes_lenient("de:ad be:ef", &[':']).unwrap();
+/// assert_eq!(bytes, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+/// ```
+///
+pub fn hex_to_bytes_lenient(hex: &str, separators: &[char]) -> Result<Vec<u8>, ByteUtilsError> {
+    let digits: Vec<(usize, char)> = hex
+        .char_indices()
+        .filter(|(_, c)| !c.is_ascii_whitespace() && !separators.contains(c))
+        .collect();
+
+    if digits.len() % 2 != 0 {
+        return Err(ByteUtilsError::OddLength);
     }
+
+    let mut bytes = Vec::with_capacity(digits.len() / 2);
+    decode_hex_digits(digits.iter().copied(), &mut bytes)?;
     Ok(bytes)
 }
 
+// Decodes pairs of hex digits and appends the bytes to `out`. Each digit carries
+// its byte offset in the caller's input so that errors point at the original
+// string. On error, `out` is truncated back to its original length.
+fn decode_hex_digits(
+    digits: impl Iterator<Item = (usize, char)>,
+    out: &mut Vec<u8>,
+) -> Result<(), ByteUtilsError> {
+    let start = out.len();
+    let mut high = None;
+    for (index, ch) in digits {
+        let Some(nibble) = hex_nibble(ch) else {
+            out.truncate(start);
+            return Err(ByteUtilsError::InvalidHexChar { index, ch });
+        };
+        match high.take() {
+            Some(high) => out.push((high << 4) | nibble),
+            None => high = Some(nibble),
+        }
+    }
+    Ok(())
+}
+
+// Returns the value of a hex digit of either case, or `None` if `ch` is not one.
+fn hex_nibble(ch: char) -> Option<u8> {
+    ch.to_digit(16).map(|value| value as u8)
+}
+
+/// Method-style hex encoding for byte slices.
+///
+/// This trait is implemented for `[u8]`, so it is also available on `Vec<u8>`,
+/// arrays and other types that dereference to a byte slice. It delegates to
+/// `bytes_to_hex`.
+///
+/// # Example
+/// ```rust
+/// use byteutils::ToHex;
+///
+/// assert_eq!(vec![0xDE, 0xAD].to_hex(), "dead");
+/// assert_eq!([0x0F, 0x80].to_hex(), "0f80");
+/// ```
+pub trait ToHex {
+    /// Returns the lowercase hexadecimal representation of `self`.
+    fn to_hex(&self) -> String;
+}
+
+impl ToHex for [u8] {
+    fn to_hex(&self) -> String {
+        bytes_to_hex(self)
+    }
+}
+
+/// Method-style hex decoding for strings.
+///
+/// This trait is implemented for `str`, so it is also available on `String`.
+/// It delegates to `hex_to_bytes` and accepts the same input.
+///
+/// # Example
+/// ```rust
+/// use byteutils::FromHex;
+///
+/// assert_eq!("deadbeef".from_hex().unwrap(), vec![0xDE, 0xAD, 0xBE, 0xEF]);
+/// assert!(String::from("0g").from_hex().is_err());
+/// ```
+pub trait FromHex {
+    /// Decodes `self` as a hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `hex_to_bytes`.
+    // The method reads as a conversion *from* hex text held by `self`.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_hex(&self) -> Result<Vec<u8>, ByteUtilsError>;
+}
+
+impl FromHex for str {
+    fn from_hex(&self) -> Result<Vec<u8>, ByteUtilsError> {
+        hex_to_bytes(self)
+    }
+}
+
 /// Converts a byte slice to a UTF-8 string.
 ///
 /// This function takes a slice of bytes and attempts to convert it to a UTF-8 string.
@@ -85,7 +401,9 @@ pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, String> {
 ///
 /// A Result containing either:
 /// - Ok(String): The UTF-8 string representation of the input bytes.
-/// - Err(String): An error message if the bytes are not valid UTF-8.
+/// - Err(ByteUtilsError): `InvalidUtf8` if the bytes are not valid UTF-8. The error
+///   carries the length of the valid prefix and of the invalid sequence that follows,
+///   so callers can recover the decodable text or skip past the bad bytes.
 ///
 /// # Example
 /// ```rust
@@ -94,8 +412,34 @@ pub fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, String> {
 /// assert_eq!(string, "Hello");
 /// ```
 ///
-pub fn bytes_to_string(bytes: &[u8]) -> Result<String, String> {
-    String::from_utf8(bytes.to_vec()).map_err(|e| format!("Invalid UTF-8 sequence: {}", e))
+pub fn bytes_to_string(bytes: &[u8]) -> Result<String, ByteUtilsError> {
+    core::str::from_utf8(bytes)
+        .map(String::from)
+        .map_err(|e| ByteUtilsError::InvalidUtf8 {
+            valid_up_to: e.valid_up_to(),
+            error_len: e.error_len(),
+        })
+}
+
+/// Converts a byte slice to a UTF-8 string, replacing invalid sequences.
+///
+/// Unlike `bytes_to_string`, this function never fails: each invalid UTF-8
+/// sequence is replaced with U+FFFD REPLACEMENT CHARACTER.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to convert to a string.
+///
+/// # Returns
+///
+/// A String containing the decoded text.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(byteutils::bytes_to_string_lossy(b"ok\xFF"), "ok\u{FFFD}");
+/// ```
+pub fn bytes_to_string_lossy(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
 }
 
 /// Converts a string to its byte representation.
@@ -160,7 +504,8 @@ pub fn string_to_hex(s: &str) -> String {
 ///
 /// A Result containing either:
 /// - Ok(String): The UTF-8 string representation of the input hexadecimal.
-/// - Err(String): An error message if the input is invalid or the bytes are not valid UTF-8.
+/// - Err(ByteUtilsError): `OddLength` or `InvalidHexChar` if the input is invalid, or
+///   `InvalidUtf8` if the bytes are not valid UTF-8.
 ///
 /// # Example
 /// ```rust
@@ -169,7 +514,570 @@ pub fn string_to_hex(s: &str) -> String {
 /// assert_eq!(string, "Hello");
 /// ```
 ///
-pub fn hex_to_string(hex: &str) -> Result<String, String> {
+pub fn hex_to_string(hex: &str) -> Result<String, ByteUtilsError> {
     let bytes = hex_to_bytes(hex)?;
     bytes_to_string(&bytes)
 }
+
+/// Checks whether every byte in a slice is ASCII.
+///
+/// This function returns `true` if all bytes are in the range `0x00..=0x7F`.
+/// An empty slice is considered ASCII.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to check.
+///
+/// # Returns
+///
+/// `true` if every byte is ASCII, `false` otherwise.
+///
+/// # Example
+/// ```rust
+/// assert!(byteutils::is_ascii_bytes(b"Hello"));
+/// assert!(!byteutils::is_ascii_bytes(&[0x48, 0xFF]));
+/// ```
+///
+pub fn is_ascii_bytes(bytes: &[u8]) -> bool {
+    bytes.is_ascii()
+}
+
+/// Converts a byte slice to a binary string with a separator between bytes.
+///
+/// Each byte is rendered as eight binary digits (most significant bit first),
+/// and the groups are joined with `sep`. No separator is added before the first
+/// or after the last group.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to convert to binary.
+/// * `sep` - The separator to insert between each 8-bit group.
+///
+/// # Returns
+///
+/// A String containing the binary representation of the input bytes.
+///
+/// # Example
+/// ```rust
+/// let bytes = &[0xDE, 0xAD];
+/// assert_eq!(byteutils::bytes_to_binary_spaced(bytes, " "), "11011110 10101101");
+/// ```
+///
+pub fn bytes_to_binary_spaced(bytes: &[u8], sep: &str) -> String {
+    let mut binary =
+        String::with_capacity(bytes.len() * 8 + bytes.len().saturating_sub(1) * sep.len());
+    for (i, &byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            binary.push_str(sep);
+        }
+        binary.push_str(&format!("{:08b}", byte));
+    }
+    binary
+}
+
+/// Converts a byte slice to its binary string representation.
+///
+/// Each byte is rendered as eight binary digits, most significant bit first,
+/// with no separator between bytes. Use `bytes_to_binary_spaced` to insert one.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to convert to binary.
+///
+/// # Returns
+///
+/// A String containing the binary representation of the input bytes.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(byteutils::bytes_to_binary(&[0x05]), "00000101");
+/// assert_eq!(byteutils::bytes_to_binary(&[0xDE, 0xAD]), "1101111010101101");
+/// ```
+///
+pub fn bytes_to_binary(bytes: &[u8]) -> String {
+    bytes_to_binary_spaced(bytes, "")
+}
+
+/// Converts a binary string to its byte representation.
+///
+/// This is the inverse of `bytes_to_binary`. The input is read in groups of
+/// eight digits, most significant bit first, so its length must be a multiple
+/// of 8 and it may only contain `0` and `1`.
+///
+/// # Arguments
+///
+/// * `s` - A string slice containing the binary representation to convert.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The byte representation of the input binary string.
+/// - Err(ByteUtilsError): `InvalidBinaryLength` or `InvalidBinaryChar` if the input is invalid.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(byteutils::binary_to_bytes("1101111010101101").unwrap(), vec![0xDE, 0xAD]);
+/// assert!(byteutils::binary_to_bytes("0101").is_err());
+/// ```
+///
+pub fn binary_to_bytes(s: &str) -> Result<Vec<u8>, ByteUtilsError> {
+    if s.len() % 8 != 0 {
+        return Err(ByteUtilsError::InvalidBinaryLength { len: s.len() });
+    }
+
+    let mut bytes = Vec::with_capacity(s.len() / 8);
+    let mut byte = 0u8;
+    for (index, ch) in s.char_indices() {
+        let bit = match ch {
+            '0' => 0,
+            '1' => 1,
+            _ => return Err(ByteUtilsError::InvalidBinaryChar { index, ch }),
+        };
+        byte = (byte << 1) | bit;
+        if index % 8 == 7 {
+            bytes.push(byte);
+        }
+    }
+    Ok(bytes)
+}
+
+/// Returns the positions of the set bits in a byte.
+///
+/// Bits are numbered from the least significant bit (LSB = 0) to the most
+/// significant bit (MSB = 7). Positions are returned in ascending order.
+///
+/// # Arguments
+///
+/// * `byte` - The byte to inspect.
+///
+/// # Returns
+///
+/// A Vec<u8> containing the indices (0-7) of every set bit.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(byteutils::set_bit_positions(0b1000_0101), vec![0, 2, 7]);
+/// assert!(byteutils::set_bit_positions(0).is_empty());
+/// ```
+///
+pub fn set_bit_positions(byte: u8) -> Vec<u8> {
+    (0..8).filter(|&bit| byte & (1 << bit) != 0).collect()
+}
+
+/// Returns the global positions of the set bits in a byte slice.
+///
+/// Bits are numbered LSB-first within each byte (LSB = 0), and byte `i`
+/// contributes bit indices `i * 8` through `i * 8 + 7`. Positions are
+/// returned in ascending order.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to inspect.
+///
+/// # Returns
+///
+/// A Vec<usize> containing the global index of every set bit.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(byteutils::set_bit_positions_slice(&[0b0000_0001, 0b1000_0000]), vec![0, 15]);
+/// ```
+///
+pub fn set_bit_positions_slice(bytes: &[u8]) -> Vec<usize> {
+    bytes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &byte)| {
+            set_bit_positions(byte)
+                .into_iter()
+                .map(move |bit| i * 8 + bit as usize)
+        })
+        .collect()
+}
+
+/// Builds a byte with the given bit positions set.
+///
+/// This is the inverse of `set_bit_positions`. Bits are numbered from the
+/// least significant bit (LSB = 0) to the most significant bit (MSB = 7).
+/// Duplicate positions are allowed and have no additional effect.
+///
+/// # Arguments
+///
+/// * `positions` - The bit indices (0-7) to set.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(u8): The byte with every requested bit set.
+/// - Err(ByteUtilsError): `InvalidBitPosition` if any position is 8 or greater.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(byteutils::bitmask_from_positions(&[0, 2, 7]).unwrap(), 0b1000_0101);
+/// assert!(byteutils::bitmask_from_positions(&[8]).is_err());
+/// ```
+///
+pub fn bitmask_from_positions(positions: &[u8]) -> Result<u8, ByteUtilsError> {
+    positions.iter().try_fold(0u8, |mask, &position| {
+        if position >= 8 {
+            return Err(ByteUtilsError::InvalidBitPosition { position });
+        }
+        Ok(mask | (1 << position))
+    })
+}
+
+/// Compares two hexadecimal strings for byte equality in constant time.
+///
+/// Both inputs are decoded with `hex_to_bytes` and the resulting bytes are
+/// compared by accumulating the XOR of every pair, so the comparison does not
+/// return early at the first differing byte. This makes it suitable for
+/// comparing secrets such as HMACs or API tokens.
+///
+/// Inputs that decode to a different number of bytes return `false` immediately,
+/// since length is not usually secret. Invalid hex on either side also returns
+/// `false`, because a malformed token is simply not equal.
+///
+/// # Arguments
+///
+/// * `a` - The first hexadecimal string.
+/// * `b` - The second hexadecimal string.
+///
+/// # Returns
+///
+/// `true` if both inputs are valid hex and decode to identical bytes, `false` otherwise.
+///
+/// # Example
+/// ```rust
+/// assert!(byteutils::hex_eq_constant_time("deadBEEF", "DEADbeef"));
+/// assert!(!byteutils::hex_eq_constant_time("deadbeef", "deadbeee"));
+/// assert!(!byteutils::hex_eq_constant_time("deadbeef", "not hex!"));
+/// ```
+///
+pub fn hex_eq_constant_time(a: &str, b: &str) -> bool {
+    let (Ok(a), Ok(b)) = (hex_to_bytes(a), hex_to_bytes(b)) else {
+        return false;
+    };
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let diff = a.iter().zip(&b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(diff) == 0
+}
+
+/// Truncates a UTF-8 byte slice without splitting a multi-byte character.
+///
+/// This function returns the longest prefix of `bytes` that is at most `max_len`
+/// bytes long and ends on a UTF-8 character boundary. The input is assumed to be
+/// valid UTF-8 up to the cut point; no other validation is performed.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of UTF-8 encoded bytes.
+/// * `max_len` - The maximum length of the returned prefix, in bytes.
+///
+/// # Returns
+///
+/// A prefix of `bytes` that does not end in the middle of a character.
+///
+/// # Example
+/// ```rust
+/// let bytes = "a🦀b".as_bytes();
+/// assert_eq!(byteutils::truncate_bytes_at_char_boundary(bytes, 3), b"a");
+/// assert_eq!(byteutils::truncate_bytes_at_char_boundary(bytes, 5), "a🦀".as_bytes());
+/// ```
+///
+pub fn truncate_bytes_at_char_boundary(bytes: &[u8], max_len: usize) -> &[u8] {
+    if max_len >= bytes.len() {
+        return bytes;
+    }
+
+    let mut end = max_len;
+    while end > 0 && (bytes[end] & 0b1100_0000) == 0b1000_0000 {
+        end -= 1;
+    }
+    &bytes[..end]
+}
+
+/// Formats a byte slice as a 16-bytes-per-line hex dump in the `hexdump -C` layout.
+///
+/// Each line has an 8-digit hexadecimal offset, sixteen hex pairs split into two
+/// groups of eight, and an ASCII column in which non-printable bytes are shown
+/// as `.`. The final partial line is padded so the ASCII column stays aligned.
+/// Unlike `hexdump -C`, the output has no closing line holding the total length,
+/// and repeated lines are not collapsed into `*`. This is not the `xxd` layout,
+/// which groups bytes in pairs. It is shorthand for `hexdump_width(bytes, 16)`.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to dump.
+///
+/// # Returns
+///
+/// A String containing the formatted dump, or an empty String for empty input.
+///
+/// # Example
+/// ```rust
+/// let dump = byteutils::hexdump(b"Hello, world!\n");
+/// assert_eq!(
+///     dump,
+///     "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a        |Hello, world!.|\n"
+/// );
+/// ```
+///
+pub fn hexdump(bytes: &[u8]) -> String {
+    hexdump_width(bytes, 16)
+}
+
+/// Formats a byte slice as a hex dump with a configurable number of bytes per line.
+///
+/// Each line starts with the 8-digit hexadecimal offset of its first byte,
+/// followed by the bytes as space-separated hex pairs (with an extra space after
+/// every 8 bytes) and an ASCII column between `|` characters in which
+/// non-printable bytes are shown as `.`. The hex column of the final line is
+/// padded so the ASCII column stays aligned. Every line ends with `\n`.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to dump.
+/// * `bytes_per_line` - The number of bytes shown on each line.
+///
+/// # Returns
+///
+/// A String containing the formatted dump, or an empty String for empty input.
+///
+/// # Panics
+///
+/// This function will panic if `bytes_per_line` is 0.
+///
+/// # Example
+/// ```rust
+/// let dump = byteutils::hexdump_width(b"Hello!", 4);
+/// assert_eq!(dump, "00000000  48 65 6c 6c  |Hell|\n00000004  6f 21        |o!|\n");
+/// ```
+///
+pub fn hexdump_width(bytes: &[u8], bytes_per_line: usize) -> String {
+    assert!(bytes_per_line > 0, "bytes_per_line must be greater than 0");
+
+    let mut dump = String::new();
+    for (line, chunk) in bytes.chunks(bytes_per_line).enumerate() {
+        dump.push_str(&format!("{:08x}  ", line * bytes_per_line));
+        for i in 0..bytes_per_line {
+            if i > 0 && i % 8 == 0 {
+                dump.push(' ');
+            }
+            match chunk.get(i) {
+                Some(byte) => dump.push_str(&format!("{:02x} ", byte)),
+                None => dump.push_str("   "),
+            }
+        }
+
+        dump.push_str(" |");
+        for &byte in chunk {
+            dump.push(if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            });
+        }
+        dump.push_str("|\n");
+    }
+    dump
+}
+
+/// Guesses the hash algorithm of a hex-encoded digest from its length.
+///
+/// The input is decoded with `hex_to_bytes` first, so it must be valid hex. The
+/// algorithm is then inferred from the digest length: 16 bytes for MD5, 20 for
+/// SHA-1, 32 for SHA-256 and 64 for SHA-512. This is only a heuristic, since
+/// other algorithms share these lengths.
+///
+/// # Arguments
+///
+/// * `hex` - A string slice containing the hex-encoded digest.
+///
+/// # Returns
+///
+/// `Some` with the likely algorithm name, or `None` if the input is not valid hex
+/// or its length does not match a known algorithm.
+///
+/// # Example
+/// ```rust
+/// assert_eq!(byteutils::identify_hash("d41d8cd98f00b204e9800998ecf8427e"), Some("MD5"));
+/// assert_eq!(byteutils::identify_hash("abcd"), None);
+/// ```
+///
+pub fn identify_hash(hex: &str) -> Option<&'static str> {
+    match hex_to_bytes(hex).ok()?.len() {
+        16 => Some("MD5"),
+        20 => Some("SHA-1"),
+        32 => Some("SHA-256"),
+        64 => Some("SHA-512"),
+        _ => None,
+    }
+}
+
+/// Splits a byte slice into fixed-size chunks that may overlap.
+///
+/// This function returns borrowed sub-slices of length `size`, starting at offset
+/// 0 and advancing by `step` bytes each time. A `step` smaller than `size` makes
+/// consecutive chunks overlap, while a larger `step` skips bytes between them.
+/// Only full-length chunks are returned, so trailing bytes that do not fill a
+/// whole chunk are left out.
+///
+/// # Arguments
+///
+/// * `data` - A slice of bytes to split.
+/// * `size` - The length of each chunk.
+/// * `step` - The distance between the starts of consecutive chunks.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<&[u8]>): The chunks, in order.
+/// - Err(ByteUtilsError): `InvalidChunkSize` if `size` or `step` is 0.
+///
+/// # Example
+/// ```rust
+/// let chunks = byteutils::overlapping_chunks(&[1, 2, 3, 4, 5], 3, 2).unwrap();
+/// assert_eq!(chunks, vec![&[1, 2, 3][..], &[3, 4, 5][..]]);
+/// ```
+///
+pub fn overlapping_chunks(
+    data: &[u8],
+    size: usize,
+    step: usize,
+) -> Result<Vec<&[u8]>, ByteUtilsError> {
+    if size == 0 || step == 0 {
+        return Err(ByteUtilsError::InvalidChunkSize { size, step });
+    }
+
+    Ok(data.windows(size).step_by(step).collect())
+}
+
+/// Collapses runs of ASCII whitespace in a byte slice into single spaces.
+///
+/// Every run of spaces, tabs, carriage returns and line feeds is replaced by one
+/// space, and leading and trailing whitespace is removed. All other bytes are
+/// copied unchanged, so the input does not need to be valid UTF-8.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of bytes to normalize.
+///
+/// # Returns
+///
+/// A Vec<u8> with whitespace runs collapsed and trimmed.
+///
+/// # Example
+/// ```rust
+/// let collapsed = byteutils::collapse_whitespace_bytes(b"  Host:\t example.com \r\n");
+/// assert_eq!(collapsed, b"Host: example.com");
+/// ```
+///
+pub fn collapse_whitespace_bytes(bytes: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut pending_space = false;
+    for &byte in bytes {
+        if matches!(byte, b' ' | b'\t' | b'\r' | b'\n') {
+            pending_space = !result.is_empty();
+        } else {
+            if pending_space {
+                result.push(b' ');
+                pending_space = false;
+            }
+            result.push(byte);
+        }
+    }
+    result
+}
+
+/// Splits interleaved multi-channel bytes into one buffer per channel.
+///
+/// Byte `i` of `data` belongs to channel `i % channels`, so `[L0, R0, L1, R1]`
+/// with two channels becomes `[[L0, L1], [R0, R1]]`. This converts interleaved
+/// audio or sensor samples into a planar layout.
+///
+/// # Arguments
+///
+/// * `data` - The interleaved bytes.
+/// * `channels` - The number of channels.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<Vec<u8>>): One Vec per channel, in channel order.
+/// - Err(ByteUtilsError): `InvalidChannelLayout` if `channels` is 0 or the length
+///   of `data` is not a multiple of `channels`.
+///
+/// # Example
+/// ```rust
+/// let planar = byteutils::deinterleave(&[1, 10, 2, 20, 3, 30], 2).unwrap();
+/// assert_eq!(planar, vec![vec![1, 2, 3], vec![10, 20, 30]]);
+/// ```
+///
+pub fn deinterleave(data: &[u8], channels: usize) -> Result<Vec<Vec<u8>>, ByteUtilsError> {
+    if channels == 0 || data.len() % channels != 0 {
+        return Err(ByteUtilsError::InvalidChannelLayout {
+            len: data.len(),
+            channels,
+        });
+    }
+
+    let mut planar: Vec<Vec<u8>> = (0..channels)
+        .map(|_| Vec::with_capacity(data.len() / channels))
+        .collect();
+    for frame in data.chunks_exact(channels) {
+        for (channel, &byte) in planar.iter_mut().zip(frame) {
+            channel.push(byte);
+        }
+    }
+    Ok(planar)
+}
+
+/// Combines per-channel buffers into interleaved bytes.
+///
+/// This is the inverse of `deinterleave`: the output takes one byte from each
+/// channel in turn, so `[[L0, L1], [R0, R1]]` becomes `[L0, R0, L1, R1]`. An
+/// empty list of channels produces an empty Vec.
+///
+/// # Arguments
+///
+/// * `channels` - The planar buffers, one per channel.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(Vec<u8>): The interleaved bytes.
+/// - Err(ByteUtilsError): `ChannelLengthMismatch` if the channels do not all have
+///   the same length as the first one.
+///
+/// # Example
+/// ```rust
+/// let interleaved = byteutils::interleave_channels(&[vec![1, 2, 3], vec![10, 20, 30]]).unwrap();
+/// assert_eq!(interleaved, vec![1, 10, 2, 20, 3, 30]);
+/// ```
+///
+pub fn interleave_channels(channels: &[Vec<u8>]) -> Result<Vec<u8>, ByteUtilsError> {
+    let Some(first) = channels.first() else {
+        return Ok(Vec::new());
+    };
+    let expected = first.len();
+    if let Some((channel, other)) = channels
+        .iter()
+        .enumerate()
+        .find(|(_, other)| other.len() != expected)
+    {
+        return Err(ByteUtilsError::ChannelLengthMismatch {
+            channel,
+            expected,
+            actual: other.len(),
+        });
+    }
+
+    let mut interleaved = Vec::with_capacity(expected * channels.len());
+    for i in 0..expected {
+        interleaved.extend(channels.iter().map(|channel| channel[i]));
+    }
+    Ok(interleaved)
+}
@@ -0,0 +1,335 @@
+use crate::ByteUtilsError;
+
+/// Decodes a big-endian IEEE 754 single-precision float.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of exactly 4 bytes, most significant byte first.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(f32): The decoded value.
+/// - Err(ByteUtilsError): `InvalidLength` if the slice is not exactly 4 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// let value = byteutils::num::bytes_to_f32_be(&[0x3F, 0xC0, 0x00, 0x00]).unwrap();
+/// assert_eq!(value, 1.5);
+/// ```
+pub fn bytes_to_f32_be(bytes: &[u8]) -> Result<f32, ByteUtilsError> {
+    Ok(f32::from_be_bytes(to_array(bytes)?))
+}
+
+/// Decodes a little-endian IEEE 754 single-precision float.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of exactly 4 bytes, least significant byte first.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(f32): The decoded value.
+/// - Err(ByteUtilsError): `InvalidLength` if the slice is not exactly 4 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// let value = byteutils::num::bytes_to_f32_le(&[0x00, 0x00, 0xC0, 0x3F]).unwrap();
+/// assert_eq!(value, 1.5);
+/// ```
+pub fn bytes_to_f32_le(bytes: &[u8]) -> Result<f32, ByteUtilsError> {
+    Ok(f32::from_le_bytes(to_array(bytes)?))
+}
+
+/// Decodes a big-endian IEEE 754 double-precision float.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of exactly 8 bytes, most significant byte first.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(f64): The decoded value.
+/// - Err(ByteUtilsError): `InvalidLength` if the slice is not exactly 8 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// let bytes = [0x3F, 0xF8, 0, 0, 0, 0, 0, 0];
+/// assert_eq!(byteutils::num::bytes_to_f64_be(&bytes).unwrap(), 1.5);
+/// ```
+pub fn bytes_to_f64_be(bytes: &[u8]) -> Result<f64, ByteUtilsError> {
+    Ok(f64::from_be_bytes(to_array(bytes)?))
+}
+
+/// Decodes a little-endian IEEE 754 double-precision float.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of exactly 8 bytes, least significant byte first.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(f64): The decoded value.
+/// - Err(ByteUtilsError): `InvalidLength` if the slice is not exactly 8 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// let bytes = [0, 0, 0, 0, 0, 0, 0xF8, 0x3F];
+/// assert_eq!(byteutils::num::bytes_to_f64_le(&bytes).unwrap(), 1.5);
+/// ```
+pub fn bytes_to_f64_le(bytes: &[u8]) -> Result<f64, ByteUtilsError> {
+    Ok(f64::from_le_bytes(to_array(bytes)?))
+}
+
+/// Encodes a single-precision float as big-endian bytes.
+///
+/// This is the inverse of `bytes_to_f32_be`.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::num::f32_to_bytes_be(1.5), [0x3F, 0xC0, 0x00, 0x00]);
+/// ```
+pub fn f32_to_bytes_be(value: f32) -> [u8; 4] {
+    value.to_be_bytes()
+}
+
+/// Encodes a single-precision float as little-endian bytes.
+///
+/// This is the inverse of `bytes_to_f32_le`.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::num::f32_to_bytes_le(1.5), [0x00, 0x00, 0xC0, 0x3F]);
+/// ```
+pub fn f32_to_bytes_le(value: f32) -> [u8; 4] {
+    value.to_le_bytes()
+}
+
+/// Encodes a double-precision float as big-endian bytes.
+///
+/// This is the inverse of `bytes_to_f64_be`.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::num::f64_to_bytes_be(1.5), [0x3F, 0xF8, 0, 0, 0, 0, 0, 0]);
+/// ```
+pub fn f64_to_bytes_be(value: f64) -> [u8; 8] {
+    value.to_be_bytes()
+}
+
+/// Encodes a double-precision float as little-endian bytes.
+///
+/// This is the inverse of `bytes_to_f64_le`.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::num::f64_to_bytes_le(1.5), [0, 0, 0, 0, 0, 0, 0xF8, 0x3F]);
+/// ```
+pub fn f64_to_bytes_le(value: f64) -> [u8; 8] {
+    value.to_le_bytes()
+}
+
+/// Encodes a `u16` as bytes in the requested byte order.
+///
+/// # Arguments
+///
+/// * `value` - The value to encode.
+/// * `big_endian` - `true` for big-endian (network) order, `false` for little-endian.
+///
+/// # Returns
+///
+/// An array of 2 bytes holding the encoded value.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::num::u16_to_bytes(0x1234, true), [0x12, 0x34]);
+/// assert_eq!(byteutils::num::u16_to_bytes(0x1234, false), [0x34, 0x12]);
+/// ```
+pub fn u16_to_bytes(value: u16, big_endian: bool) -> [u8; 2] {
+    if big_endian {
+        value.to_be_bytes()
+    } else {
+        value.to_le_bytes()
+    }
+}
+
+/// Decodes a `u16` from bytes in the requested byte order.
+///
+/// This is the inverse of `u16_to_bytes`.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of exactly 2 bytes.
+/// * `big_endian` - `true` for big-endian (network) order, `false` for little-endian.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(u16): The decoded value.
+/// - Err(ByteUtilsError): `InvalidLength` if the slice is not exactly 2 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// let bytes = byteutils::hex_to_bytes("1234").unwrap();
+/// assert_eq!(byteutils::num::bytes_to_u16(&bytes, true).unwrap(), 0x1234);
+/// ```
+pub fn bytes_to_u16(bytes: &[u8], big_endian: bool) -> Result<u16, ByteUtilsError> {
+    let bytes = to_array(bytes)?;
+    Ok(if big_endian {
+        u16::from_be_bytes(bytes)
+    } else {
+        u16::from_le_bytes(bytes)
+    })
+}
+
+/// Encodes a `u32` as bytes in the requested byte order.
+///
+/// # Arguments
+///
+/// * `value` - The value to encode.
+/// * `big_endian` - `true` for big-endian (network) order, `false` for little-endian.
+///
+/// # Returns
+///
+/// An array of 4 bytes holding the encoded value.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::num::u32_to_bytes(0xDEADBEEF, true), [0xDE, 0xAD, 0xBE, 0xEF]);
+/// assert_eq!(byteutils::num::u32_to_bytes(0xDEADBEEF, false), [0xEF, 0xBE, 0xAD, 0xDE]);
+/// ```
+pub fn u32_to_bytes(value: u32, big_endian: bool) -> [u8; 4] {
+    if big_endian {
+        value.to_be_bytes()
+    } else {
+        value.to_le_bytes()
+    }
+}
+
+/// Decodes a `u32` from bytes in the requested byte order.
+///
+/// This is the inverse of `u32_to_bytes`.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of exactly 4 bytes.
+/// * `big_endian` - `true` for big-endian (network) order, `false` for little-endian.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(u32): The decoded value.
+/// - Err(ByteUtilsError): `InvalidLength` if the slice is not exactly 4 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// let bytes = byteutils::hex_to_bytes("deadbeef").unwrap();
+/// assert_eq!(byteutils::num::bytes_to_u32(&bytes, true).unwrap(), 0xDEADBEEF);
+/// ```
+pub fn bytes_to_u32(bytes: &[u8], big_endian: bool) -> Result<u32, ByteUtilsError> {
+    let bytes = to_array(bytes)?;
+    Ok(if big_endian {
+        u32::from_be_bytes(bytes)
+    } else {
+        u32::from_le_bytes(bytes)
+    })
+}
+
+/// Encodes a `u64` as bytes in the requested byte order.
+///
+/// # Arguments
+///
+/// * `value` - The value to encode.
+/// * `big_endian` - `true` for big-endian (network) order, `false` for little-endian.
+///
+/// # Returns
+///
+/// An array of 8 bytes holding the encoded value.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::num::u64_to_bytes(0x0102030405060708, true), [1, 2, 3, 4, 5, 6, 7, 8]);
+/// assert_eq!(byteutils::num::u64_to_bytes(0x0102030405060708, false), [8, 7, 6, 5, 4, 3, 2, 1]);
+/// ```
+pub fn u64_to_bytes(value: u64, big_endian: bool) -> [u8; 8] {
+    if big_endian {
+        value.to_be_bytes()
+    } else {
+        value.to_le_bytes()
+    }
+}
+
+/// Decodes a `u64` from bytes in the requested byte order.
+///
+/// This is the inverse of `u64_to_bytes`.
+///
+/// # Arguments
+///
+/// * `bytes` - A slice of exactly 8 bytes.
+/// * `big_endian` - `true` for big-endian (network) order, `false` for little-endian.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(u64): The decoded value.
+/// - Err(ByteUtilsError): `InvalidLength` if the slice is not exactly 8 bytes long.
+///
+/// # Example
+///
+/// ```rust
+/// let bytes = byteutils::hex_to_bytes("0102030405060708").unwrap();
+/// assert_eq!(byteutils::num::bytes_to_u64(&bytes, true).unwrap(), 0x0102030405060708);
+/// ```
+pub fn bytes_to_u64(bytes: &[u8], big_endian: bool) -> Result<u64, ByteUtilsError> {
+    let bytes = to_array(bytes)?;
+    Ok(if big_endian {
+        u64::from_be_bytes(bytes)
+    } else {
+        u64::from_le_bytes(bytes)
+    })
+}
+
+/// Flips the byte order of a multi-byte value in place.
+///
+/// Reversing the bytes of a big-endian integer yields its little-endian
+/// encoding and vice versa. Unlike `vec::reverse_in_place`, which takes a
+/// `&mut Vec`, this works on any mutable byte slice, including fixed-size arrays.
+///
+/// # Arguments
+///
+/// * `bytes` - The encoded value to flip.
+///
+/// # Example
+///
+/// ```rust
+/// let mut bytes = byteutils::num::u32_to_bytes(0x01020304, true);
+/// byteutils::num::swap_endianness(&mut bytes);
+/// assert_eq!(bytes, byteutils::num::u32_to_bytes(0x01020304, false));
+/// ```
+pub fn swap_endianness(bytes: &mut [u8]) {
+    bytes.reverse();
+}
+
+// Copies a slice into a fixed-size array, failing if the lengths differ.
+fn to_array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], ByteUtilsError> {
+    bytes.try_into().map_err(|_| ByteUtilsError::InvalidLength {
+        expected: N,
+        actual: bytes.len(),
+    })
+}
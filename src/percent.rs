@@ -0,0 +1,84 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{hex_nibble, ByteUtilsError, HEX_UPPER};
+
+/// Percent-encodes a string for use in a URL.
+///
+/// Every byte of the UTF-8 input outside the RFC 3986 unreserved set
+/// (`A-Z`, `a-z`, `0-9`, `-`, `.`, `_`, `~`) is written as `%XX` with uppercase
+/// hex digits. Spaces become `%20`, not `+`.
+///
+/// # Arguments
+///
+/// * `input` - The string to encode.
+///
+/// # Returns
+///
+/// A String containing only unreserved characters and `%XX` escapes.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::percent::percent_encode("a b&c=é"), "a%20b%26c%3D%C3%A9");
+/// assert_eq!(byteutils::percent::percent_encode("safe-._~"), "safe-._~");
+/// ```
+pub fn percent_encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for &byte in input.as_bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push('%');
+            out.push(HEX_UPPER[(byte >> 4) as usize] as char);
+            out.push(HEX_UPPER[(byte & 0x0F) as usize] as char);
+        }
+    }
+    out
+}
+
+/// Decodes a percent-encoded string.
+///
+/// Each `%XX` escape (with either case of hex digit) is replaced by the byte it
+/// encodes, and all other characters are copied unchanged. `+` is not treated
+/// as a space. The decoded bytes must form valid UTF-8.
+///
+/// # Arguments
+///
+/// * `input` - The percent-encoded string to decode.
+///
+/// # Returns
+///
+/// A Result containing either:
+/// - Ok(String): The decoded string.
+/// - Err(ByteUtilsError): `InvalidPercentEscape` if a `%` is not followed by two hex
+///   digits, or `InvalidUtf8` if the decoded bytes are not valid UTF-8.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::percent::percent_decode("a%20b%26c%3d%C3%A9").unwrap(), "a b&c=é");
+/// assert!(byteutils::percent::percent_decode("100%").is_err());
+/// ```
+pub fn percent_decode(input: &str) -> Result<String, ByteUtilsError> {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            decoded.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+
+        let high = bytes.get(i + 1).and_then(|&b| hex_nibble(b as char));
+        let low = bytes.get(i + 2).and_then(|&b| hex_nibble(b as char));
+        let byte = match (high, low) {
+            (Some(high), Some(low)) => (high << 4) | low,
+            _ => return Err(ByteUtilsError::InvalidPercentEscape { index: i }),
+        };
+        decoded.push(byte);
+        i += 3;
+    }
+    crate::bytes_to_string(&decoded)
+}
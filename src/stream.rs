@@ -0,0 +1,93 @@
+use alloc::vec::Vec;
+
+/// A buffer that splits incrementally received bytes into complete lines.
+///
+/// Data can be pushed in arbitrarily sized pieces, for example as it arrives from
+/// a socket. Complete `\n`-terminated lines are then taken out one at a time with
+/// `next_line`, while a trailing partial line stays buffered until the rest of it
+/// is pushed.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteutils::stream::LineBuffer;
+///
+/// let mut lines = LineBuffer::new();
+/// lines.push(b"hello\r\nwor");
+/// assert_eq!(lines.next_line(), Some(b"hello".to_vec()));
+/// assert_eq!(lines.next_line(), None);
+///
+/// lines.push(b"ld\n");
+/// assert_eq!(lines.next_line(), Some(b"world".to_vec()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LineBuffer {
+    buffer: Vec<u8>,
+    // Offset of the first byte that has not been returned yet.
+    start: usize,
+    // Offset up to which the unread bytes are known to contain no `\n`.
+    scanned: usize,
+}
+
+impl LineBuffer {
+    /// Creates an empty line buffer.
+    pub fn new() -> Self {
+        LineBuffer {
+            buffer: Vec::new(),
+            start: 0,
+            scanned: 0,
+        }
+    }
+
+    /// Appends received data to the buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The bytes to append.
+    pub fn push(&mut self, data: &[u8]) {
+        // Drop already returned lines once they make up at least half of the
+        // buffer, so compaction costs amortized O(1) per byte.
+        if self.start > 0 && self.start >= self.buffer.len() - self.start {
+            self.buffer.drain(..self.start);
+            self.scanned -= self.start;
+            self.start = 0;
+        }
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Removes and returns the next complete line from the buffer.
+    ///
+    /// The returned line does not include the terminating `\n`, nor a `\r`
+    /// directly before it.
+    ///
+    /// # Returns
+    ///
+    /// `Some` with the next line, or `None` if the buffer does not contain a
+    /// complete line yet.
+    pub fn next_line(&mut self) -> Option<Vec<u8>> {
+        let Some(offset) = self.buffer[self.scanned..].iter().position(|&b| b == b'\n') else {
+            self.scanned = self.buffer.len();
+            return None;
+        };
+        let end = self.scanned + offset;
+        let mut line = &self.buffer[self.start..end];
+        if line.last() == Some(&b'\r') {
+            line = &line[..line.len() - 1];
+        }
+        let line = line.to_vec();
+
+        self.start = end + 1;
+        self.scanned = self.start;
+        if self.start == self.buffer.len() {
+            self.buffer.clear();
+            self.start = 0;
+            self.scanned = 0;
+        }
+        Some(line)
+    }
+
+    /// Returns the buffered bytes that do not form a complete line yet.
+    pub fn remaining(&self) -> &[u8] {
+        &self.buffer[self.start..]
+    }
+}
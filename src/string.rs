@@ -1,6 +1,11 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
-use regex::Regex;
+#[cfg(feature = "regex")]
+use regex::RegexSet;
+
+#[cfg(feature = "regex")]
+use crate::ByteUtilsError;
 
 /// Converts a comma-separated string into a vector of strings.
 ///
@@ -24,17 +29,151 @@ use regex::Regex;
 /// assert_eq!(result, vec!["hello", "world", "rust"]);
 /// ```
 pub fn to_array(comma_separated_values: &str) -> Vec<String> {
-    comma_separated_values
-        .split(',')
+    to_array_with_delimiter(comma_separated_values, ',')
+}
+
+/// Converts a delimiter-separated string into a vector of strings.
+///
+/// This function behaves like `to_array`, but splits on the supplied delimiter
+/// instead of a comma. Each element is trimmed of whitespace and empty elements
+/// are filtered out.
+///
+/// # Arguments
+///
+/// * `input` - A string slice containing delimiter-separated values.
+/// * `delimiter` - The character to split on.
+///
+/// # Returns
+///
+/// A Vec<String> containing the processed strings from the input.
+///
+/// # Example
+///
+/// ```rust
+/// let result = byteutils::string::to_array_with_delimiter("a | b || c", '|');
+/// assert_eq!(result, vec!["a", "b", "c"]);
+/// ```
+pub fn to_array_with_delimiter(input: &str, delimiter: char) -> Vec<String> {
+    input
+        .split(delimiter)
         .map(str::trim)
         .filter(|s| !s.is_empty())
         .map(String::from)
         .collect()
 }
 
+/// Converts a delimiter-separated string into a vector of strings, keeping empty fields.
+///
+/// Unlike `to_array_with_delimiter`, empty elements are preserved so positional
+/// data keeps its shape. Each element is still trimmed of whitespace. An empty
+/// input yields a single empty field.
+///
+/// # Arguments
+///
+/// * `input` - A string slice containing delimiter-separated values.
+/// * `delimiter` - The character to split on.
+///
+/// # Returns
+///
+/// A Vec<String> containing one trimmed string per field.
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::string::to_array_keep_empty("a, ,c", ','), vec!["a", "", "c"]);
+/// assert_eq!(byteutils::string::to_array_keep_empty("a,b,", ','), vec!["a", "b", ""]);
+/// ```
+pub fn to_array_keep_empty(input: &str, delimiter: char) -> Vec<String> {
+    input
+        .split(delimiter)
+        .map(str::trim)
+        .map(String::from)
+        .collect()
+}
+
+/// Joins strings with a separator between them.
+///
+/// This is the inverse of `to_array`: joining with `","` and splitting the result
+/// again with `to_array` gives back the trimmed, non-empty parts. No separator is
+/// added before the first or after the last part, and an empty slice yields an
+/// empty string.
+///
+/// # Arguments
+///
+/// * `parts` - The strings to join.
+/// * `separator` - The string placed between consecutive parts.
+///
+/// # Returns
+///
+/// A String containing the parts separated by `separator`.
+///
+/// # Example
+///
+/// ```rust
+/// let parts = byteutils::string::to_array("a, b ,c");
+/// let joined = byteutils::string::join_strings(&parts, ", ");
+/// assert_eq!(joined, "a, b, c");
+/// assert_eq!(byteutils::string::to_array(&joined), parts);
+/// ```
+pub fn join_strings(parts: &[String], separator: &str) -> String {
+    parts.join(separator)
+}
+
+/// Splits text into owned lines.
+///
+/// Lines are terminated by either `\n` or `\r\n`, and the terminators are not
+/// included in the result. A single trailing line terminator does not produce an
+/// empty last element, so `"a\nb\n"` and `"a\nb"` both yield `["a", "b"]`; input
+/// that ends with a blank line, such as `"a\n\n"`, keeps it as `["a", ""]`.
+/// Whitespace inside lines is preserved.
+///
+/// # Arguments
+///
+/// * `input` - The text to split.
+///
+/// # Returns
+///
+/// A Vec<String> containing one string per line.
+///
+/// # Example
+///
+/// ```rust
+/// let lines = byteutils::string::lines_to_vec("first\r\nsecond\nthird\n");
+/// assert_eq!(lines, vec!["first", "second", "third"]);
+/// ```
+pub fn lines_to_vec(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+/// Joins lines into a single string separated by `\n`.
+///
+/// No trailing newline is appended, so `vec_to_lines(&lines_to_vec(s))` returns
+/// `s` with `\r\n` normalized to `\n` and any single trailing newline removed.
+///
+/// # Arguments
+///
+/// * `lines` - The lines to join.
+///
+/// # Returns
+///
+/// A String containing the lines separated by `\n`.
+///
+/// # Example
+///
+/// ```rust
+/// let lines = vec!["first".to_string(), "second".to_string()];
+/// assert_eq!(byteutils::string::vec_to_lines(&lines), "first\nsecond");
+/// ```
+pub fn vec_to_lines(lines: &[String]) -> String {
+    lines.join("\n")
+}
+
 /// Escapes special characters in a SQL string by replacing backslashes with double backslashes
 /// and single quotes with double single quotes.
 ///
+/// This targets ANSI SQL style string literals, as used by PostgreSQL and SQLite.
+/// For MySQL in its default mode, use `escape_sql_mysql` instead.
+///
 /// # Arguments
 ///
 /// * `input` - The string to escape
@@ -54,6 +193,48 @@ pub fn escape_sql(input: &str) -> String {
     input.replace('\\', "\\\\").replace('\'', "''")
 }
 
+/// Escapes special characters in a string for a MySQL string literal.
+///
+/// MySQL in its default SQL mode treats backslash as an escape character, so this
+/// function escapes quotes with a backslash rather than by doubling them. It also
+/// escapes the control characters MySQL requires: NUL, newline, carriage return
+/// and Ctrl-Z.
+///
+/// This targets MySQL and MariaDB. For ANSI SQL (PostgreSQL, SQLite), use
+/// `escape_sql` instead.
+///
+/// # Arguments
+///
+/// * `input` - The string to escape
+///
+/// # Returns
+///
+/// A String with MySQL special characters properly escaped
+///
+/// # Example
+///
+/// ```rust
+/// let input = "O'Connor\n\"Path\"";
+/// let escaped = byteutils::string::escape_sql_mysql(input);
+/// assert_eq!(escaped, "O\\'Connor\\n\\\"Path\\\"");
+/// ```
+pub fn escape_sql_mysql(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '\0' => escaped.push_str("\\0"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\x1A' => escaped.push_str("\\Z"),
+            '\'' => escaped.push_str("\\'"),
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Encloses a string in single quotes for SQL string literals.
 ///
 /// # Arguments
@@ -75,11 +256,220 @@ pub fn enclose_quotes(name: &str) -> String {
     format!("'{}'", name)
 }
 
+/// Quotes a string as a single argument for a POSIX shell.
+///
+/// The argument is wrapped in single quotes, inside which the shell treats every
+/// character literally. Each embedded single quote is written as `'\''`, which
+/// closes the quoted section, adds an escaped quote and reopens it. An empty
+/// string becomes `''`.
+///
+/// This targets POSIX `sh` and compatible shells such as `bash` and `zsh`. It is
+/// not suitable for Windows `cmd.exe` or PowerShell.
+///
+/// # Arguments
+///
+/// * `arg` - The argument to quote
+///
+/// # Returns
+///
+/// A String that the shell parses back into exactly `arg`
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::string::shell_quote("O'Reilly"), r"'O'\''Reilly'");
+/// assert_eq!(byteutils::string::shell_quote("$HOME; rm -rf /"), "'$HOME; rm -rf /'");
+/// ```
+pub fn shell_quote(arg: &str) -> String {
+    format!("'{}'", arg.replace('\'', r"'\''"))
+}
+
+/// Quotes a SQL identifier such as a table or column name.
+///
+/// The name is wrapped in double quotes, as in standard SQL, and any embedded
+/// double quote is escaped by doubling it. NUL characters are removed because
+/// most databases do not allow them in identifiers.
+///
+/// # Arguments
+///
+/// * `name` - The identifier to quote
+///
+/// # Returns
+///
+/// A String containing the quoted identifier
+///
+/// # Example
+///
+/// ```rust
+/// assert_eq!(byteutils::string::escape_identifier("users"), "\"users\"");
+/// assert_eq!(byteutils::string::escape_identifier("my\"table"), "\"my\"\"table\"");
+/// ```
+pub fn escape_identifier(name: &str) -> String {
+    let mut quoted = String::with_capacity(name.len() + 2);
+    quoted.push('"');
+    for c in name.chars() {
+        match c {
+            '\0' => {}
+            '"' => quoted.push_str("\"\""),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Escapes a string for safe interpolation into HTML text or attribute values.
+///
+/// The characters `&`, `<`, `>`, `"` and `'` are replaced with `&amp;`, `&lt;`,
+/// `&gt;`, `&quot;` and `&#39;`. The input is processed in a single pass, so the
+/// `&` of an inserted entity is never escaped a second time. Existing entities
+/// in the input are escaped like any other text.
+///
+/// # Arguments
+///
+/// * `input` - The string to escape
+///
+/// # Returns
+///
+/// A String with HTML special characters replaced by entities
+///
+/// # Example
+///
+/// ```rust
+/// let escaped = byteutils::string::escape_html("<a href=\"x\">Tom & Jerry's</a>");
+/// assert_eq!(escaped, "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;");
+/// ```
+pub fn escape_html(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Decodes HTML entities produced by `escape_html` and numeric character references.
+///
+/// The named entities `&amp;`, `&lt;`, `&gt;`, `&quot;` and `&apos;` are decoded,
+/// as are decimal (`&#39;`) and hexadecimal (`&#x27;`) references to valid Unicode
+/// scalar values. Anything else, including unknown entities and a `&` without a
+/// terminating `;`, is left unchanged.
+///
+/// # Arguments
+///
+/// * `input` - The string to unescape
+///
+/// # Returns
+///
+/// A String with the recognized entities decoded
+///
+/// # Example
+///
+/// ```rust
+/// let text = byteutils::string::unescape_html("Tom &amp; Jerry&#39;s &#x1F980; &copy;");
+/// assert_eq!(text, "Tom & Jerry's 🦀 &copy;");
+/// ```
+pub fn unescape_html(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let entity = rest
+            .find(';')
+            .and_then(|semi| Some((decode_html_entity(&rest[1..semi])?, semi)));
+        match entity {
+            Some((c, semi)) => {
+                result.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+// Decodes the name of an HTML entity (the text between `&` and `;`).
+fn decode_html_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let number = name.strip_prefix('#')?;
+            let (digits, radix) = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => (hex, 16),
+                None => (number, 10),
+            };
+            if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+                return None;
+            }
+            char::from_u32(u32::from_str_radix(digits, radix).ok()?)
+        }
+    }
+}
+
+/// Escapes a string for use inside a JSON string literal.
+///
+/// Quotes and backslashes are escaped, the control characters `\n`, `\r`, `\t`,
+/// backspace and form feed use their short escapes, and every other character
+/// below U+0020 is written as `\u00XX`. All other characters, including
+/// non-ASCII ones, are copied unchanged since JSON allows raw UTF-8. The
+/// surrounding quotes are not added.
+///
+/// # Arguments
+///
+/// * `input` - The string to escape
+///
+/// # Returns
+///
+/// A String that can be placed between double quotes in a JSON document
+///
+/// # Example
+///
+/// ```rust
+/// let escaped = byteutils::string::escape_json("say \"hi\"\n\u{1}é");
+/// assert_eq!(escaped, "say \\\"hi\\\"\\n\\u0001é");
+/// ```
+pub fn escape_json(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '\x08' => escaped.push_str("\\b"),
+            '\x0C' => escaped.push_str("\\f"),
+            '\0'..='\x1F' => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 /// Checks if a given word is present in the source string as a whole word, ignoring case.
 ///
-/// This function creates a case-insensitive regular expression pattern that matches the given word
-/// as a whole word (bounded by word boundaries) and checks if this pattern
-/// is found in the source string.
+/// This function scans the source string for case-insensitive occurrences of the word
+/// and accepts one that is bounded by word boundaries on both sides, with the same
+/// meaning as `\b` in a regular expression: a boundary lies between a word character
+/// and a non-word character, or between a word character and the start or end of
+/// the string. Word characters are alphanumeric characters (as defined by
+/// `char::is_alphanumeric`) and `_`. No regular expression is compiled.
 ///
 /// # Arguments
 ///
@@ -99,9 +489,37 @@ pub fn enclose_quotes(name: &str) -> String {
 /// assert!(!byteutils::string::is_contain_word(source, "Rust!"));
 /// ```
 pub fn is_contain_word(src: &str, word: &str) -> bool {
-    let pattern = format!(r"(?i)\b{}\b", regex::escape(word));
-    let re = Regex::new(&pattern).unwrap();
-    re.is_match(src)
+    let is_boundary = |i: usize| {
+        let before = src[..i].chars().next_back().is_some_and(is_word_char);
+        let after = src[i..].chars().next().is_some_and(is_word_char);
+        before != after
+    };
+
+    src.char_indices()
+        .map(|(i, _)| i)
+        .chain([src.len()])
+        .any(|start| {
+            prefix_len_ignore_case(&src[start..], word)
+                .is_some_and(|len| is_boundary(start) && is_boundary(start + len))
+        })
+}
+
+// Returns whether `c` counts as a word character for `is_contain_word`.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Returns the byte length of the prefix of `src` that equals `word` ignoring case,
+// or `None` if `src` does not start with `word`.
+fn prefix_len_ignore_case(src: &str, word: &str) -> Option<usize> {
+    let mut chars = src.char_indices();
+    for expected in word.chars() {
+        let (_, actual) = chars.next()?;
+        if actual != expected && !actual.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(src.len(), |(i, _)| i))
 }
 
 /// Checks if any of the given words are present in the source string as whole words.
@@ -134,11 +552,145 @@ pub fn has_contain_words(src: &str, words: &[String]) -> bool {
     words.iter().any(|word| is_contain_word(src, word))
 }
 
+/// Returns the words from a list that are present in the source string as whole words.
+///
+/// This function checks each word with `is_contain_word`, so matching is
+/// case-insensitive and bounded by word boundaries. The result keeps the order of
+/// the input list and contains each matching word only once.
+///
+/// # Arguments
+///
+/// * `src` - A string slice that holds the text to search in.
+/// * `words` - A slice of String values representing the words to search for.
+///
+/// # Returns
+///
+/// A Vec<String> of the matching words. An empty Vec means no word matched.
+///
+/// # Examples
+///
+/// ```rust
+/// let source = "The quick brown fox jumps over the lazy dog.";
+/// let words = vec!["fox".to_string(), "cat".to_string(), "QUICK".to_string()];
+/// assert_eq!(byteutils::string::find_contain_words(source, &words), vec!["fox", "QUICK"]);
+/// ```
+pub fn find_contain_words(src: &str, words: &[String]) -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+    for word in words {
+        if !found.contains(word) && is_contain_word(src, word) {
+            found.push(word.clone());
+        }
+    }
+    found
+}
+
+/// A precompiled matcher for finding whole words from a fixed word list.
+///
+/// `has_contain_words` scans the source string once per word. `WordMatcher`
+/// compiles the patterns for a whole word list into a single regular expression
+/// set once, so the same list can be checked against many strings cheaply.
+/// Matching uses the same case-insensitive, word-boundary semantics as
+/// `is_contain_word`.
+///
+/// The words are compiled as a `RegexSet` rather than one alternation such as
+/// `\b(?i:apple|cherry)\b`. An alternation only reports non-overlapping matches,
+/// so in "New York" it would find `new york` but not `york`, while a set reports
+/// every word that matches anywhere.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteutils::string::WordMatcher;
+///
+/// let words = vec!["apple".to_string(), "cherry".to_string()];
+/// let matcher = WordMatcher::new(&words).unwrap();
+///
+/// assert!(matcher.is_match("I like Apple pie"));
+/// assert!(!matcher.is_match("I like pineapple"));
+/// assert_eq!(matcher.matches("cherry and apple"), vec!["apple", "cherry"]);
+/// ```
+#[cfg(feature = "regex")]
+#[derive(Debug, Clone)]
+pub struct WordMatcher {
+    words: Vec<String>,
+    set: RegexSet,
+}
+
+#[cfg(feature = "regex")]
+impl WordMatcher {
+    /// Compiles a matcher for the given word list.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - A slice of String values representing the words to search for.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing either:
+    /// - Ok(WordMatcher): The compiled matcher.
+    /// - Err(ByteUtilsError): `EmptyWordList` if `words` is empty, or `InvalidPattern`
+    ///   if the patterns cannot be compiled.
+    pub fn new(words: &[String]) -> Result<Self, ByteUtilsError> {
+        if words.is_empty() {
+            return Err(ByteUtilsError::EmptyWordList);
+        }
+
+        // One pattern per word, so that overlapping words are all reported.
+        let patterns = words
+            .iter()
+            .map(|word| format!(r"(?i)\b{}\b", regex::escape(word)));
+        let set = RegexSet::new(patterns).map_err(|e| ByteUtilsError::InvalidPattern {
+            message: e.to_string(),
+        })?;
+
+        Ok(WordMatcher {
+            words: words.to_vec(),
+            set,
+        })
+    }
+
+    /// Checks if any word of the list is present in the source string as a whole word.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - A string slice that holds the text to search in.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if any word is found, `false` otherwise.
+    pub fn is_match(&self, src: &str) -> bool {
+        self.set.is_match(src)
+    }
+
+    /// Returns the words of the list that are present in the source string as whole words.
+    ///
+    /// The result keeps the order of the word list given to `new` and contains each
+    /// word only once.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - A string slice that holds the text to search in.
+    ///
+    /// # Returns
+    ///
+    /// A Vec<String> of the matching words.
+    pub fn matches(&self, src: &str) -> Vec<String> {
+        let mut found: Vec<String> = Vec::new();
+        for index in self.set.matches(src).iter() {
+            let word = &self.words[index];
+            if !found.contains(word) {
+                found.push(word.clone());
+            }
+        }
+        found
+    }
+}
+
 /// Replaces placeholders in a string with specified replacement values.
 ///
 /// This function takes a string containing placeholders in the format `{{placeholder}}` and
-/// replaces them with the specified replacement value. It uses regex pattern matching to
-/// find and replace all occurrences of the placeholder.
+/// replaces them with the specified replacement value. All occurrences of the placeholder
+/// are replaced, and the replacement value is inserted literally.
 ///
 /// # Arguments
 ///
@@ -157,20 +709,45 @@ pub fn has_contain_words(src: &str, words: &[String]) -> bool {
 /// let result = byteutils::string::replace_placeholder(template, "name", "John");
 /// assert_eq!(result, "Hello John! Welcome to {{place}}.");
 /// ```
-///
-/// # Panics
-///
-/// This function will panic if the regex pattern creation fails, which should only happen
-/// if the placeholder contains characters that make an invalid regex pattern.
 pub fn replace_placeholder(input: &str, placeholder: &str, replacement: &str) -> String {
-    // Create a regex pattern that matches {{placeholder}} exactly
-    let pattern = format!(r"\{{\{{{}}}\}}", regex::escape(placeholder));
-
-    // Compile the regex pattern - using unwrap is safe here because we control the pattern format
-    let re = Regex::new(&pattern).expect("Failed to create regex pattern");
+    replace_placeholder_delimited(input, placeholder, replacement, "{{", "}}")
+}
 
-    // Replace all occurrences and return the result
-    re.replace_all(input, replacement).into_owned()
+/// Replaces placeholders wrapped in custom delimiters with a replacement value.
+///
+/// This function behaves like `replace_placeholder`, but the placeholder is wrapped
+/// in the given `open` and `close` delimiters instead of `{{` and `}}`. Delimiters
+/// are matched literally, so characters such as `$` or `(` are safe to use.
+///
+/// # Arguments
+///
+/// * `input` - A string slice that contains the text with placeholders
+/// * `placeholder` - A string slice representing the placeholder name (without delimiters)
+/// * `replacement` - A string slice containing the value to replace the placeholder with
+/// * `open` - The opening delimiter, for example `"${"` or `"<<"`
+/// * `close` - The closing delimiter, for example `"}"` or `">>"`
+///
+/// # Returns
+///
+/// Returns a new String with all occurrences of the placeholder replaced with the replacement value.
+///
+/// # Examples
+///
+/// ```rust
+/// let template = "Hello ${name}! Welcome to <<place>>.";
+/// let result = byteutils::string::replace_placeholder_delimited(template, "name", "John", "${", "}");
+/// assert_eq!(result, "Hello John! Welcome to <<place>>.");
+/// ```
+pub fn replace_placeholder_delimited(
+    input: &str,
+    placeholder: &str,
+    replacement: &str,
+    open: &str,
+    close: &str,
+) -> String {
+    // Match open + placeholder + close literally and replace every occurrence
+    let target = format!("{}{}{}", open, placeholder, close);
+    input.replace(&target, replacement)
 }
 
 /// Replaces multiple placeholders in a string using a map of placeholder-value pairs.
@@ -180,6 +757,11 @@ pub fn replace_placeholder(input: &str, placeholder: &str, replacement: &str) ->
 /// If a placeholder in the template doesn't have a corresponding entry in the map, it remains
 /// unchanged in the output string.
 ///
+/// Placeholders are replaced one entry at a time, in the map's iteration order. A value
+/// that itself contains `{{placeholder}}` is expanded again only if that placeholder's
+/// entry is visited later, so the result of such templates depends on iteration order.
+/// Use `replace_multiple_placeholders_once` for a result that does not.
+///
 /// # Arguments
 ///
 /// * `template` - A string slice containing the template text with placeholders
@@ -215,3 +797,1356 @@ pub fn replace_multiple_placeholders(
 
     result
 }
+
+/// Replaces multiple placeholders in a single pass over the template.
+///
+/// This function scans the template once from left to right and replaces each
+/// `{{placeholder}}` that has an entry in the map with its value. Substituted
+/// values are never scanned again, so a value such as `{{name}}` stays literal,
+/// and the result does not depend on the map's iteration order. Placeholders
+/// without a corresponding entry are left unchanged.
+///
+/// # Arguments
+///
+/// * `template` - A string slice containing the template text with placeholders
+/// * `replacements` - A HashMap where keys are placeholder names and values are their replacements
+///
+/// # Returns
+///
+/// Returns a new String with all matched placeholders replaced with their corresponding values.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// let mut replacements = HashMap::new();
+/// replacements.insert("outer".to_string(), "{{inner}}".to_string());
+/// replacements.insert("inner".to_string(), "value".to_string());
+///
+/// let result = byteutils::string::replace_multiple_placeholders_once("{{outer}} {{inner}}", &replacements);
+/// assert_eq!(result, "{{inner}} value");
+/// ```
+pub fn replace_multiple_placeholders_once(
+    template: &str,
+    replacements: &HashMap<String, String>,
+) -> String {
+    render_placeholders(template, replacements).0
+}
+
+/// Replaces multiple placeholders and reports the ones that could not be resolved.
+///
+/// This function renders the template exactly like `replace_multiple_placeholders_once`,
+/// and additionally returns the names of placeholders that appear in the template but
+/// have no entry in the map. The list contains each name once, in order of first
+/// occurrence. Substituted placeholders never appear in it.
+///
+/// # Arguments
+///
+/// * `template` - A string slice containing the template text with placeholders
+/// * `replacements` - A HashMap where keys are placeholder names and values are their replacements
+///
+/// # Returns
+///
+/// A tuple of the rendered String and a Vec<String> of missing placeholder names.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// let mut replacements = HashMap::new();
+/// replacements.insert("name".to_string(), "John".to_string());
+///
+/// let template = "{{greeting}} {{name}}, {{greeting}} from {{city}}";
+/// let (result, missing) =
+///     byteutils::string::replace_multiple_placeholders_checked(template, &replacements);
+/// assert_eq!(result, "{{greeting}} John, {{greeting}} from {{city}}");
+/// assert_eq!(missing, vec!["greeting", "city"]);
+/// ```
+pub fn replace_multiple_placeholders_checked(
+    template: &str,
+    replacements: &HashMap<String, String>,
+) -> (String, Vec<String>) {
+    render_placeholders(template, replacements)
+}
+
+// Scans the template once, substituting known `{{placeholder}}`s and collecting the
+// distinct names of unknown ones in order of first occurrence.
+fn render_placeholders(
+    template: &str,
+    replacements: &HashMap<String, String>,
+) -> (String, Vec<String>) {
+    let mut result = String::with_capacity(template.len());
+    let mut missing: Vec<String> = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        let after_open = &rest[start + 2..];
+        let name = after_open.find("}}").map(|end| &after_open[..end]);
+
+        // Substitute known placeholders; otherwise keep one `{` and rescan from the next
+        // character, so a placeholder directly after a stray brace is still found
+        match name.and_then(|name| replacements.get(name).map(|value| (name, value))) {
+            Some((name, value)) => {
+                result.push_str(value);
+                rest = &after_open[name.len() + 2..];
+            }
+            None => {
+                // A name starting with `{` or containing `{{` is not a placeholder at
+                // this position; the rescan picks up the real one further on
+                if let Some(name) = name.filter(|n| !n.starts_with('{') && !n.contains("{{")) {
+                    if !missing.iter().any(|m| m == name) {
+                        missing.push(name.to_string());
+                    }
+                }
+                result.push('{');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    (result, missing)
+}
+
+/// Computes a normalized similarity score between two strings.
+///
+/// The score is derived from the Levenshtein edit distance between `a` and `b`,
+/// divided by the length (in characters) of the longer string and subtracted from 1.
+/// The result is clamped to the range `0.0..=1.0`, where `1.0` means the strings are
+/// identical. Two empty strings are considered identical.
+///
+/// # Arguments
+///
+/// * `a` - The first string to compare
+/// * `b` - The second string to compare
+///
+/// # Returns
+///
+/// An `f64` between `0.0` and `1.0` describing how similar the two strings are.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::similarity_ratio("kitten", "kitten"), 1.0);
+/// assert_eq!(byteutils::string::similarity_ratio("", ""), 1.0);
+/// assert_eq!(byteutils::string::similarity_ratio("abcd", "abce"), 0.75);
+/// assert_eq!(byteutils::string::similarity_ratio("abc", "xyz"), 0.0);
+/// ```
+pub fn similarity_ratio(a: &str, b: &str) -> f64 {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let distance = levenshtein(a, b);
+    (1.0 - distance as f64 / max_len as f64).clamp(0.0, 1.0)
+}
+
+/// Computes the Levenshtein edit distance between two strings.
+///
+/// The distance is the minimum number of single-character insertions, deletions
+/// and substitutions needed to turn `a` into `b`. Characters are counted as
+/// Unicode scalar values, so an emoji counts as one unit. Only one row of the
+/// dynamic programming table is kept, sized to the shorter string.
+///
+/// # Arguments
+///
+/// * `a` - The first string to compare
+/// * `b` - The second string to compare
+///
+/// # Returns
+///
+/// The edit distance between `a` and `b` (`0` if they are identical).
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(byteutils::string::levenshtein("🦀", "🦞"), 1);
+/// assert_eq!(byteutils::string::levenshtein("same", "same"), 0);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let (long, short) = if a.chars().count() >= b.chars().count() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let short_chars: Vec<char> = short.chars().collect();
+    let mut row: Vec<usize> = (0..=short_chars.len()).collect();
+
+    for (i, cl) in long.chars().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+        for (j, &cs) in short_chars.iter().enumerate() {
+            let cost = if cl == cs { 0 } else { 1 };
+            let next = (row[j] + 1).min(row[j + 1] + 1).min(prev_diag + cost);
+            prev_diag = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+
+    row[short_chars.len()]
+}
+
+/// Removes diacritical marks from Latin characters in a string.
+///
+/// Accented Latin letters (for example `é`, `ñ`, `Ü`) are folded to their base
+/// ASCII letter, and standalone combining marks (U+0300 to U+036F) are dropped.
+/// Characters without a known base letter are kept unchanged. This is intended for
+/// building accent-insensitive search keys rather than full Unicode normalization.
+///
+/// # Arguments
+///
+/// * `s` - The string to strip diacritics from
+///
+/// # Returns
+///
+/// A new String with diacritics removed.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::remove_diacritics("café"), "cafe");
+/// assert_eq!(byteutils::string::remove_diacritics("Ångström"), "Angstrom");
+/// assert_eq!(byteutils::string::remove_diacritics("cafe\u{301}"), "cafe");
+/// ```
+pub fn remove_diacritics(s: &str) -> String {
+    s.chars()
+        .filter(|c| !('\u{0300}'..='\u{036F}').contains(c))
+        .map(fold_diacritic)
+        .collect()
+}
+
+// Maps a precomposed Latin character to its unaccented base letter.
+fn fold_diacritic(c: char) -> char {
+    match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => 'A',
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => 'C',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'Ď' | 'Đ' => 'D',
+        'ď' | 'đ' => 'd',
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => 'E',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => 'G',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'Ĥ' | 'Ħ' => 'H',
+        'ĥ' | 'ħ' => 'h',
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => 'I',
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+        'Ĵ' => 'J',
+        'ĵ' => 'j',
+        'Ķ' => 'K',
+        'ķ' => 'k',
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => 'L',
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => 'N',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => 'O',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'Ŕ' | 'Ŗ' | 'Ř' => 'R',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => 'S',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'Ţ' | 'Ť' | 'Ŧ' => 'T',
+        'ţ' | 'ť' | 'ŧ' => 't',
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => 'U',
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'Ŵ' => 'W',
+        'ŵ' => 'w',
+        'Ý' | 'Ŷ' | 'Ÿ' => 'Y',
+        'ý' | 'ÿ' | 'ŷ' => 'y',
+        'Ź' | 'Ż' | 'Ž' => 'Z',
+        'ź' | 'ż' | 'ž' => 'z',
+        _ => c,
+    }
+}
+
+/// Checks whether every character in a string is ASCII.
+///
+/// # Arguments
+///
+/// * `s` - The string to check
+///
+/// # Returns
+///
+/// `true` if every character is ASCII (an empty string counts as ASCII), `false` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(byteutils::string::is_ascii_str("Hello, world!"));
+/// assert!(!byteutils::string::is_ascii_str("café"));
+/// ```
+pub fn is_ascii_str(s: &str) -> bool {
+    s.is_ascii()
+}
+
+/// Truncates a string to a maximum byte length without splitting a character.
+///
+/// This function returns the longest prefix of `s` that is at most `max_bytes`
+/// bytes long and ends on a character boundary, so it never panics the way
+/// slicing at an arbitrary byte index can. No allocation is performed.
+///
+/// # Arguments
+///
+/// * `s` - The string to truncate
+/// * `max_bytes` - The maximum length of the returned prefix, in bytes
+///
+/// # Returns
+///
+/// A prefix of `s` that is at most `max_bytes` bytes long.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::truncate_str("🦀abc", 2), "");
+/// assert_eq!(byteutils::string::truncate_str("🦀abc", 5), "🦀a");
+/// assert_eq!(byteutils::string::truncate_str("abc", 10), "abc");
+/// ```
+pub fn truncate_str(s: &str, max_bytes: usize) -> &str {
+    if max_bytes >= s.len() {
+        return s;
+    }
+
+    let mut end = max_bytes;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Removes a prefix from a string, ignoring ASCII case.
+///
+/// The prefix is compared with `eq_ignore_ascii_case`, so only ASCII letters are
+/// folded. The returned remainder borrows from `s`.
+///
+/// # Arguments
+///
+/// * `s` - The string to strip
+/// * `prefix` - The prefix to remove
+///
+/// # Returns
+///
+/// `Some` with the rest of `s` if it starts with `prefix` in any ASCII case,
+/// `None` otherwise, including when `prefix` is longer than `s`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::strip_prefix_ci("BEARER abc", "bearer "), Some("abc"));
+/// assert_eq!(byteutils::string::strip_prefix_ci("Basic abc", "bearer "), None);
+/// ```
+pub fn strip_prefix_ci<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = s.get(..prefix.len())?;
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+/// Removes a suffix from a string, ignoring ASCII case.
+///
+/// This is the suffix counterpart of `strip_prefix_ci`.
+///
+/// # Arguments
+///
+/// * `s` - The string to strip
+/// * `suffix` - The suffix to remove
+///
+/// # Returns
+///
+/// `Some` with the start of `s` if it ends with `suffix` in any ASCII case,
+/// `None` otherwise, including when `suffix` is longer than `s`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::strip_suffix_ci("report.PDF", ".pdf"), Some("report"));
+/// assert_eq!(byteutils::string::strip_suffix_ci("pdf", "report.pdf"), None);
+/// ```
+pub fn strip_suffix_ci<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let start = s.len().checked_sub(suffix.len())?;
+    let tail = s.get(start..)?;
+    if tail.eq_ignore_ascii_case(suffix) {
+        Some(&s[..start])
+    } else {
+        None
+    }
+}
+
+/// Truncates a string to a maximum number of characters, appending an ellipsis.
+///
+/// Characters are counted as Unicode scalar values, so an emoji counts as one. If
+/// `s` has more than `max_chars` characters, the first `max_chars` are kept and
+/// `…` is appended; otherwise `s` is returned unchanged. The ellipsis is not
+/// counted towards `max_chars`.
+///
+/// # Arguments
+///
+/// * `s` - The string to truncate
+/// * `max_chars` - The maximum number of characters to keep
+///
+/// # Returns
+///
+/// A String containing `s`, shortened and ending in `…` if it was too long.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::truncate_with_ellipsis("Hello, world", 5), "Hello…");
+/// assert_eq!(byteutils::string::truncate_with_ellipsis("🦀🦀🦀", 3), "🦀🦀🦀");
+/// assert_eq!(byteutils::string::truncate_with_ellipsis("abc", 0), "…");
+/// ```
+pub fn truncate_with_ellipsis(s: &str, max_chars: usize) -> String {
+    match s.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", &s[..end]),
+        None => s.to_string(),
+    }
+}
+
+/// Pads a string on the left to a minimum width in characters.
+///
+/// Width is counted in Unicode scalar values, not bytes. If `s` is shorter than
+/// `width`, copies of `fill` are prepended until it is exactly `width`
+/// characters long; otherwise `s` is returned unchanged.
+///
+/// # Arguments
+///
+/// * `s` - The string to pad
+/// * `width` - The minimum width of the result, in characters
+/// * `fill` - The character used for padding
+///
+/// # Returns
+///
+/// A String that is at least `width` characters long.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::pad_left("42", 5, '0'), "00042");
+/// assert_eq!(byteutils::string::pad_left("é", 3, '*'), "**é");
+/// ```
+pub fn pad_left(s: &str, width: usize, fill: char) -> String {
+    let padding = width.saturating_sub(s.chars().count());
+    let mut padded: String = std::iter::repeat(fill).take(padding).collect();
+    padded.push_str(s);
+    padded
+}
+
+/// Pads a string on the right to a minimum width in characters.
+///
+/// This behaves like `pad_left`, except that the copies of `fill` are appended
+/// after `s` instead of before it.
+///
+/// # Arguments
+///
+/// * `s` - The string to pad
+/// * `width` - The minimum width of the result, in characters
+/// * `fill` - The character used for padding
+///
+/// # Returns
+///
+/// A String that is at least `width` characters long.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::pad_right("name", 6, '.'), "name..");
+/// assert_eq!(byteutils::string::pad_right("toolong", 3, ' '), "toolong");
+/// ```
+pub fn pad_right(s: &str, width: usize, fill: char) -> String {
+    let padding = width.saturating_sub(s.chars().count());
+    let mut padded = s.to_string();
+    padded.extend(std::iter::repeat(fill).take(padding));
+    padded
+}
+
+/// Normalizes whitespace to single spaces.
+///
+/// Leading and trailing whitespace is removed, and every internal run of Unicode
+/// whitespace (spaces, tabs, newlines and so on) is replaced with a single ASCII
+/// space.
+///
+/// # Arguments
+///
+/// * `s` - The string to normalize
+///
+/// # Returns
+///
+/// A String with its words separated by single spaces.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::collapse_whitespace("a\t\t b\n c "), "a b c");
+/// ```
+pub fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reverses a string by Unicode scalar value.
+///
+/// Multi-byte characters such as emoji stay intact, so the result is always
+/// valid UTF-8. Grapheme clusters are not kept together: a combining mark ends
+/// up before the character it modified, and multi-codepoint emoji (flags, ZWJ
+/// sequences) are split. Handling those requires Unicode segmentation, which
+/// this crate does not depend on.
+///
+/// # Arguments
+///
+/// * `s` - The string to reverse
+///
+/// # Returns
+///
+/// A String containing the characters of `s` in reverse order.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::reverse_string("🦀abc"), "cba🦀");
+/// ```
+pub fn reverse_string(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+/// Converts an identifier or phrase to `snake_case`.
+///
+/// The input is split into words at `_`, `-` and whitespace, and at case changes
+/// such as `helloWorld` or `HTTPServer`. Empty segments produced by leading,
+/// trailing or repeated separators are dropped. The words are lowercased and
+/// joined with `_`.
+///
+/// # Arguments
+///
+/// * `s` - The identifier or phrase to convert
+///
+/// # Returns
+///
+/// A String containing the `snake_case` form of the input.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::to_snake_case("helloWorld"), "hello_world");
+/// assert_eq!(byteutils::string::to_snake_case("Hello World"), "hello_world");
+/// assert_eq!(byteutils::string::to_snake_case("HTTPServer"), "http_server");
+/// ```
+pub fn to_snake_case(s: &str) -> String {
+    join_lowercase(s, "_")
+}
+
+/// Converts an identifier or phrase to `kebab-case`.
+///
+/// Words are split the same way as in `to_snake_case`, lowercased and joined
+/// with `-`.
+///
+/// # Arguments
+///
+/// * `s` - The identifier or phrase to convert
+///
+/// # Returns
+///
+/// A String containing the `kebab-case` form of the input.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::to_kebab_case("helloWorld"), "hello-world");
+/// assert_eq!(byteutils::string::to_kebab_case("__hello__world__"), "hello-world");
+/// ```
+pub fn to_kebab_case(s: &str) -> String {
+    join_lowercase(s, "-")
+}
+
+/// Converts an identifier or phrase to `camelCase`.
+///
+/// Words are split the same way as in `to_snake_case`. The first word is
+/// lowercased and every following word is capitalized, with no separator.
+///
+/// # Arguments
+///
+/// * `s` - The identifier or phrase to convert
+///
+/// # Returns
+///
+/// A String containing the `camelCase` form of the input.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::to_camel_case("hello_world"), "helloWorld");
+/// assert_eq!(byteutils::string::to_camel_case("Hello World"), "helloWorld");
+/// ```
+pub fn to_camel_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for (i, word) in identifier_words(s).iter().enumerate() {
+        if i == 0 {
+            result.push_str(&word.to_lowercase());
+        } else {
+            push_capitalized(&mut result, word);
+        }
+    }
+    result
+}
+
+/// Converts an identifier or phrase to `PascalCase`.
+///
+/// Words are split the same way as in `to_snake_case`. Every word is
+/// capitalized and the words are joined with no separator.
+///
+/// # Arguments
+///
+/// * `s` - The identifier or phrase to convert
+///
+/// # Returns
+///
+/// A String containing the `PascalCase` form of the input.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::to_pascal_case("hello-world"), "HelloWorld");
+/// assert_eq!(byteutils::string::to_pascal_case("helloWorld"), "HelloWorld");
+/// ```
+pub fn to_pascal_case(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for word in identifier_words(s) {
+        push_capitalized(&mut result, &word);
+    }
+    result
+}
+
+// Splits an identifier into words at `_`, `-`, whitespace and case changes. A
+// new word starts at an uppercase letter that follows a lowercase letter or a
+// digit, or that ends a run of capitals and is followed by a lowercase letter.
+fn identifier_words(s: &str) -> Vec<String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_numeric() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+// Lowercases the words of an identifier and joins them with `sep`.
+fn join_lowercase(s: &str, sep: &str) -> String {
+    identifier_words(s)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(sep)
+}
+
+// Appends `word` with its first character uppercased and the rest lowercased.
+fn push_capitalized(out: &mut String, word: &str) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        out.extend(first.to_uppercase());
+        out.push_str(&chars.as_str().to_lowercase());
+    }
+}
+
+/// Capitalizes each whitespace-separated word of a string.
+///
+/// The first character of every word is uppercased and the remaining characters
+/// are lowercased, using Unicode case mapping. Unlike `to_pascal_case`, the
+/// whitespace between words is kept exactly as it appears in the input,
+/// including leading and trailing runs.
+///
+/// # Arguments
+///
+/// * `s` - The string to convert
+///
+/// # Returns
+///
+/// A String with every word in title case.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::to_title_case("hello WORLD"), "Hello World");
+/// assert_eq!(byteutils::string::to_title_case("  élan\tvital"), "  Élan\tVital");
+/// ```
+pub fn to_title_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut at_word_start = true;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            out.push(c);
+            at_word_start = true;
+        } else if at_word_start {
+            out.extend(c.to_uppercase());
+            at_word_start = false;
+        } else {
+            out.extend(c.to_lowercase());
+        }
+    }
+    out
+}
+
+/// Converts a title or phrase into a URL-safe slug.
+///
+/// Accented Latin letters are first folded with `remove_diacritics`, then the
+/// text is lowercased. Every run of whitespace and ASCII punctuation becomes a
+/// single `-`, any other character that is not an ASCII letter or digit is
+/// dropped, and leading and trailing dashes are removed.
+///
+/// # Arguments
+///
+/// * `s` - The text to convert
+///
+/// # Returns
+///
+/// A String containing only lowercase ASCII letters, digits and single dashes.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::slugify("Hello, World!  "), "hello-world");
+/// assert_eq!(byteutils::string::slugify("Crème brûlée -- recipe"), "creme-brulee-recipe");
+/// ```
+pub fn slugify(s: &str) -> String {
+    let mut slug = String::with_capacity(s.len());
+    let mut pending_dash = false;
+    for c in remove_diacritics(s).chars() {
+        if c.is_ascii_alphanumeric() {
+            if pending_dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_dash = false;
+            slug.push(c.to_ascii_lowercase());
+        } else if c.is_whitespace() || c.is_ascii_punctuation() {
+            pending_dash = true;
+        }
+    }
+    slug
+}
+
+/// Counts the number of lines in a string.
+///
+/// Both `\n` and `\r\n` are treated as line terminators. A final line without a
+/// trailing newline is counted, but a trailing newline does not start a new line.
+///
+/// # Arguments
+///
+/// * `s` - The text to count lines in
+///
+/// # Returns
+///
+/// The number of lines in the text (`0` for an empty string).
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::count_lines("a\nb"), 2);
+/// assert_eq!(byteutils::string::count_lines("a\n"), 1);
+/// assert_eq!(byteutils::string::count_lines("a\r\nb\r\n"), 2);
+/// assert_eq!(byteutils::string::count_lines(""), 0);
+/// ```
+pub fn count_lines(s: &str) -> usize {
+    s.lines().count()
+}
+
+/// Counts the whitespace-separated words in a string.
+///
+/// Any run of Unicode whitespace separates two words, and leading or trailing
+/// whitespace is ignored.
+///
+/// # Arguments
+///
+/// * `s` - The text to count words in
+///
+/// # Returns
+///
+/// The number of words in the text (`0` for an empty or all-whitespace string).
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::count_words("  a  b "), 2);
+/// assert_eq!(byteutils::string::count_words(""), 0);
+/// ```
+pub fn count_words(s: &str) -> usize {
+    s.split_whitespace().count()
+}
+
+/// Counts the characters in a string.
+///
+/// Characters are counted as Unicode scalar values, not bytes, so `"🦀"` counts
+/// as one character even though it is four bytes long. Combining marks count as
+/// separate characters.
+///
+/// # Arguments
+///
+/// * `s` - The text to count characters in
+///
+/// # Returns
+///
+/// The number of characters in the text.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::count_chars("🦀"), 1);
+/// assert_eq!(byteutils::string::count_chars("héllo"), 5);
+/// ```
+pub fn count_chars(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Wraps text to a maximum line width while preserving existing line breaks.
+///
+/// Each line of the input is wrapped independently by greedily packing
+/// whitespace-separated words into lines of at most `width` characters, and the
+/// results are joined with `\n`. Blank lines are kept, so paragraph breaks
+/// survive. A word longer than `width` is placed on its own line rather than
+/// being split.
+///
+/// # Arguments
+///
+/// * `text` - The text to wrap
+/// * `width` - The maximum number of characters per line
+///
+/// # Returns
+///
+/// A new String with the wrapped text.
+///
+/// # Examples
+///
+/// ```rust
+/// let text = "the quick brown fox\n\njumps over the lazy dog";
+/// let wrapped = byteutils::string::wrap_text(text, 10);
+/// assert_eq!(wrapped, "the quick\nbrown fox\n\njumps over\nthe lazy\ndog");
+/// ```
+pub fn wrap_text(text: &str, width: usize) -> String {
+    text.lines()
+        .map(|line| wrap_line(line, width).join("\n"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Greedily packs the words of a single line into lines of at most `width` chars.
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in line.split_whitespace() {
+        let word_len = word.chars().count();
+        if current_len > 0 && current_len + 1 + word_len > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        if current_len > 0 {
+            current.push(' ');
+            current_len += 1;
+        }
+        current.push_str(word);
+        current_len += word_len;
+    }
+
+    lines.push(current);
+    lines
+}
+
+/// A single step in an edit script produced by `edit_script`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// The character is present in both strings.
+    Keep(char),
+    /// The character is inserted from the second string.
+    Insert(char),
+    /// The character is deleted from the first string.
+    Delete(char),
+    /// The first character is replaced by the second.
+    Substitute(char, char),
+}
+
+/// Computes a minimal sequence of edit operations that turns `a` into `b`.
+///
+/// The operations are reconstructed by backtracking the Levenshtein dynamic
+/// programming table, so the number of non-`Keep` operations equals the edit
+/// distance between the strings. Characters are compared as Unicode scalar values.
+///
+/// # Arguments
+///
+/// * `a` - The source string
+/// * `b` - The target string
+///
+/// # Returns
+///
+/// A Vec<EditOp> describing how to transform `a` into `b`, in order.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteutils::string::{edit_script, EditOp};
+///
+/// let ops = edit_script("cat", "cut");
+/// assert_eq!(ops, vec![EditOp::Keep('c'), EditOp::Substitute('a', 'u'), EditOp::Keep('t')]);
+///
+/// let ops = edit_script("ab", "b");
+/// assert_eq!(ops, vec![EditOp::Delete('a'), EditOp::Keep('b')]);
+/// ```
+///
+/// # Note
+///
+/// This function keeps the full `(len(a) + 1) * (len(b) + 1)` table in memory,
+/// so it is intended for short to medium length strings.
+pub fn edit_script(a: &str, b: &str) -> Vec<EditOp> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let cols = b.len() + 1;
+
+    let mut table = vec![0usize; (a.len() + 1) * cols];
+    for i in 0..=a.len() {
+        for j in 0..=b.len() {
+            table[i * cols + j] = if i == 0 {
+                j
+            } else if j == 0 {
+                i
+            } else {
+                let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+                (table[(i - 1) * cols + j] + 1)
+                    .min(table[i * cols + j - 1] + 1)
+                    .min(table[(i - 1) * cols + j - 1] + cost)
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(a.len().max(b.len()));
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 || j > 0 {
+        let current = table[i * cols + j];
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && current == table[(i - 1) * cols + j - 1] {
+            ops.push(EditOp::Keep(a[i - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && current == table[(i - 1) * cols + j - 1] + 1 {
+            ops.push(EditOp::Substitute(a[i - 1], b[j - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && current == table[(i - 1) * cols + j] + 1 {
+            ops.push(EditOp::Delete(a[i - 1]));
+            i -= 1;
+        } else {
+            ops.push(EditOp::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+
+    ops.reverse();
+    ops
+}
+
+/// Computes the longest common subsequence of two strings.
+///
+/// A subsequence keeps the relative order of characters but does not need to be
+/// contiguous. Characters are compared as Unicode scalar values. When several
+/// subsequences share the maximum length, one of them is returned.
+///
+/// # Arguments
+///
+/// * `a` - The first string
+/// * `b` - The second string
+///
+/// # Returns
+///
+/// A String containing a longest common subsequence of `a` and `b`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::longest_common_subsequence("ABCBDAB", "BDCABA").len(), 4);
+/// assert_eq!(byteutils::string::longest_common_subsequence("abcdef", "acf"), "acf");
+/// ```
+pub fn longest_common_subsequence(a: &str, b: &str) -> String {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let cols = b.len() + 1;
+
+    let mut table = vec![0usize; (a.len() + 1) * cols];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i * cols + j] = if a[i - 1] == b[j - 1] {
+                table[(i - 1) * cols + j - 1] + 1
+            } else {
+                table[(i - 1) * cols + j].max(table[i * cols + j - 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(table[a.len() * cols + b.len()]);
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            result.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if table[(i - 1) * cols + j] >= table[i * cols + j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+
+    result.iter().rev().collect()
+}
+
+/// Computes the length of the longest common subsequence of two strings.
+///
+/// This is a faster, lower-memory alternative to `longest_common_subsequence`
+/// when only the length is needed. It keeps two rows of the dynamic programming
+/// table, so memory use is proportional to the length of `b`.
+///
+/// # Arguments
+///
+/// * `a` - The first string
+/// * `b` - The second string
+///
+/// # Returns
+///
+/// The number of characters in a longest common subsequence of `a` and `b`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::lcs_len("ABCBDAB", "BDCABA"), 4);
+/// assert_eq!(byteutils::string::lcs_len("abc", "xyz"), 0);
+/// ```
+pub fn lcs_len(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev = vec![0usize; b.len() + 1];
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for ca in a.chars() {
+        for (j, &cb) in b.iter().enumerate() {
+            curr[j + 1] = if ca == cb {
+                prev[j] + 1
+            } else {
+                prev[j + 1].max(curr[j])
+            };
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Renames every key of a map using a conversion function.
+///
+/// This function applies `f` to each key of `map` and returns a new map with the
+/// converted keys and cloned values. It is typically combined with a case
+/// converter to adapt keys between naming conventions.
+///
+/// If two keys convert to the same new key, the value inserted last wins. Since
+/// `HashMap` iteration order is unspecified, which of the colliding values is kept
+/// is unspecified too, so avoid conversions that can collide.
+///
+/// # Arguments
+///
+/// * `map` - The map whose keys should be converted
+/// * `f` - A function that converts a key into its new name
+///
+/// # Returns
+///
+/// A new HashMap with converted keys and the original values.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert("user_name".to_string(), 1);
+///
+/// let converted = byteutils::string::convert_map_keys(&map, |k| k.replace('_', "-"));
+/// assert_eq!(converted.get("user-name"), Some(&1));
+/// ```
+pub fn convert_map_keys<V: Clone>(
+    map: &HashMap<String, V>,
+    f: impl Fn(&str) -> String,
+) -> HashMap<String, V> {
+    map.iter()
+        .map(|(key, value)| (f(key), value.clone()))
+        .collect()
+}
+
+/// Compares two dotted version strings component by component.
+///
+/// Both versions are split on `.` and compared from left to right. Components that
+/// are both numbers are compared numerically, so `1.2` is older than `1.10`; other
+/// components fall back to plain string comparison. Missing trailing components are
+/// treated as `0`, so `1.2` equals `1.2.0`.
+///
+/// Pre-release and build suffixes such as `-beta` or `+build` are not interpreted
+/// specially.
+///
+/// # Arguments
+///
+/// * `a` - The first version string
+/// * `b` - The second version string
+///
+/// # Returns
+///
+/// The `Ordering` of `a` relative to `b`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(byteutils::string::compare_versions("1.2", "1.10"), Ordering::Less);
+/// assert_eq!(byteutils::string::compare_versions("1.2", "1.2.0"), Ordering::Equal);
+/// assert_eq!(byteutils::string::compare_versions("2.0", "1.9.9"), Ordering::Greater);
+/// ```
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let mut left = a.split('.');
+    let mut right = b.split('.');
+
+    loop {
+        let (x, y) = match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (x, y) => (x.unwrap_or("0"), y.unwrap_or("0")),
+        };
+
+        let ordering = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            _ => x.cmp(y),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Extracts the distinct placeholder names used in a template.
+///
+/// This function finds every `{{placeholder}}` in the template, using the same
+/// convention as the replace functions, and returns each name once, without
+/// braces, in order of first occurrence.
+///
+/// # Arguments
+///
+/// * `template` - A string slice containing the template text with placeholders
+///
+/// # Returns
+///
+/// A Vec<String> containing the distinct placeholder names.
+///
+/// # Examples
+///
+/// ```rust
+/// let names = byteutils::string::find_placeholders("{{a}} {{b}} {{a}}");
+/// assert_eq!(names, vec!["a", "b"]);
+/// ```
+pub fn find_placeholders(template: &str) -> Vec<String> {
+    render_placeholders(template, &HashMap::new()).1
+}
+
+/// The line-ending style of a piece of text, as reported by `detect_line_ending`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// Every line ends with `\n` (Unix).
+    Lf,
+    /// Every line ends with `\r\n` (Windows).
+    CrLf,
+    /// Every line ends with a lone `\r` (classic Mac OS).
+    Cr,
+    /// More than one line-ending style is used.
+    Mixed,
+    /// The text contains no line endings.
+    None,
+}
+
+/// Detects which line-ending style a string uses.
+///
+/// The text is scanned for `\r\n`, lone `\n` and lone `\r` terminators. If only
+/// one kind is found, that style is returned; if several kinds are found, the
+/// result is `LineEnding::Mixed`.
+///
+/// # Arguments
+///
+/// * `s` - The text to inspect
+///
+/// # Returns
+///
+/// The `LineEnding` style of the text.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteutils::string::{detect_line_ending, LineEnding};
+///
+/// assert_eq!(detect_line_ending("a\nb\n"), LineEnding::Lf);
+/// assert_eq!(detect_line_ending("a\r\nb\r\n"), LineEnding::CrLf);
+/// assert_eq!(detect_line_ending("a\nb\r\n"), LineEnding::Mixed);
+/// assert_eq!(detect_line_ending("no newline"), LineEnding::None);
+/// ```
+pub fn detect_line_ending(s: &str) -> LineEnding {
+    let (mut lf, mut crlf, mut cr) = (false, false, false);
+    let mut bytes = s.bytes().peekable();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'\r' if bytes.peek() == Some(&b'\n') => {
+                bytes.next();
+                crlf = true;
+            }
+            b'\r' => cr = true,
+            b'\n' => lf = true,
+            _ => {}
+        }
+    }
+
+    match (lf, crlf, cr) {
+        (false, false, false) => LineEnding::None,
+        (true, false, false) => LineEnding::Lf,
+        (false, true, false) => LineEnding::CrLf,
+        (false, false, true) => LineEnding::Cr,
+        _ => LineEnding::Mixed,
+    }
+}
+
+/// Extracts every number in a string as a floating-point value.
+///
+/// This function finds all integer and decimal tokens, each with an optional
+/// leading `+` or `-` sign, and parses them in order of appearance. Any other
+/// text is skipped, so units and punctuation around the numbers are ignored.
+/// A `+` or `-` right after a letter or digit is not a sign, so `555-1234`
+/// yields `555` and `1234`.
+///
+/// # Arguments
+///
+/// * `s` - The text to scan for numbers
+///
+/// # Returns
+///
+/// A Vec<f64> containing the numbers found in the text.
+///
+/// # Examples
+///
+/// ```rust
+/// let numbers = byteutils::string::extract_numbers("temp 21.5C, 30F");
+/// assert_eq!(numbers, vec![21.5, 30.0]);
+/// ```
+pub fn extract_numbers(s: &str) -> Vec<f64> {
+    number_tokens(s)
+        .into_iter()
+        .filter_map(|token| token.parse().ok())
+        .collect()
+}
+
+/// Extracts every integer in a string.
+///
+/// This function finds the same tokens as `extract_numbers`, but keeps only
+/// those without a fractional part, so `21.5` is skipped rather than split into
+/// `21` and `5`. Integers that do not fit in an `i64` are skipped as well.
+///
+/// # Arguments
+///
+/// * `s` - The text to scan for integers
+///
+/// # Returns
+///
+/// A Vec<i64> containing the integers found in the text.
+///
+/// # Examples
+///
+/// ```rust
+/// let numbers = byteutils::string::extract_integers("took 120ms, 21.5% cpu, -3 retries");
+/// assert_eq!(numbers, vec![120, -3]);
+/// ```
+pub fn extract_integers(s: &str) -> Vec<i64> {
+    number_tokens(s)
+        .into_iter()
+        .filter(|token| !token.contains('.'))
+        .filter_map(|token| token.parse().ok())
+        .collect()
+}
+
+// Yields the optionally signed integer and decimal tokens of `s`. A sign only
+// counts at the start of `s` or after a character that is not alphanumeric.
+fn number_tokens(s: &str) -> Vec<&str> {
+    let bytes = s.as_bytes();
+    let is_digit_at = |i: usize| bytes.get(i).is_some_and(u8::is_ascii_digit);
+    let can_sign_at = |i: usize| {
+        !s[..i]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric)
+    };
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        if matches!(bytes[i], b'+' | b'-') && is_digit_at(i + 1) && can_sign_at(i) {
+            i += 1;
+        }
+        if !is_digit_at(i) {
+            i = start + 1;
+            continue;
+        }
+
+        while is_digit_at(i) {
+            i += 1;
+        }
+        if bytes[i..].starts_with(b".") && is_digit_at(i + 1) {
+            i += 1;
+            while is_digit_at(i) {
+                i += 1;
+            }
+        }
+        tokens.push(&s[start..i]);
+    }
+    tokens
+}
+
+/// Replaces every ASCII digit in a string with a mask character.
+///
+/// All other characters, including non-ASCII digits, are left unchanged. This
+/// is useful for redacting card numbers, phone numbers and similar values from
+/// log output while keeping their shape.
+///
+/// # Arguments
+///
+/// * `s` - The text to redact
+/// * `mask` - The character that replaces each digit
+///
+/// # Returns
+///
+/// A String with every ASCII digit replaced by `mask`.
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(byteutils::string::mask_digits("call 555-1234", '*'), "call ***-****");
+/// ```
+pub fn mask_digits(s: &str, mask: char) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_digit() { mask } else { c })
+        .collect()
+}
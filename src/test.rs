@@ -1,5 +1,15 @@
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
+use crate::base32::*;
+use crate::base58::*;
+use crate::base64::*;
+use crate::bytes::*;
+use crate::checksum::*;
+use crate::delta::*;
+use crate::num::*;
+use crate::percent::*;
+use crate::stream::*;
 use crate::string::*;
 use crate::vec::*;
 #[cfg(test)]
@@ -12,6 +22,13 @@ fn test_bytes_to_hex() {
     assert_eq!(bytes_to_hex(&[15, 255, 0, 128]), "0fff0080");
 }
 
+#[test]
+fn test_bytes_to_hex_upper() {
+    assert_eq!(bytes_to_hex_upper(&[0xDE, 0xAD, 0xBE, 0xEF]), "DEADBEEF");
+    assert_eq!(bytes_to_hex_upper(&[0x00, 0x0a]), "000A");
+    assert_eq!(bytes_to_hex_upper(&[]), "");
+}
+
 #[test]
 fn test_hex_to_bytes() {
     assert_eq!(
@@ -24,6 +41,23 @@ fn test_hex_to_bytes() {
     assert!(hex_to_bytes("0").is_err());
 }
 
+#[test]
+fn test_to_hex_trait() {
+    let bytes = vec![15, 255, 0, 128];
+    assert_eq!(bytes.to_hex(), bytes_to_hex(&bytes));
+    assert_eq!(bytes[..2].to_hex(), "0fff");
+    assert_eq!([0xABu8; 2].to_hex(), "abab");
+    assert_eq!(Vec::<u8>::new().to_hex(), "");
+}
+
+#[test]
+fn test_from_hex_trait() {
+    assert_eq!("0fff0080".from_hex().unwrap(), vec![15, 255, 0, 128]);
+    assert_eq!(String::from("0xDEAD").from_hex().unwrap(), vec![0xDE, 0xAD]);
+    assert_eq!("abc".from_hex(), Err(ByteUtilsError::OddLength));
+    assert_eq!("deadbeef".from_hex().unwrap().to_hex(), "deadbeef");
+}
+
 #[test]
 fn test_bytes_to_string() {
     // Test valid UTF-8 sequences
@@ -37,6 +71,14 @@ fn test_bytes_to_string() {
     assert!(bytes_to_string(&[0xFF, 0xFF]).is_err());
 }
 
+#[test]
+fn test_bytes_to_string_lossy() {
+    assert_eq!(bytes_to_string_lossy("🦀 Rust".as_bytes()), "🦀 Rust");
+    assert_eq!(bytes_to_string_lossy(&[0xFF, 0xFF]), "\u{FFFD}\u{FFFD}");
+    assert_eq!(bytes_to_string_lossy(&[b'a', 0xFF, b'b']), "a\u{FFFD}b");
+    assert_eq!(bytes_to_string_lossy(&[]), "");
+}
+
 #[test]
 fn test_string_to_bytes() {
     // Test ASCII string
@@ -103,249 +145,1834 @@ fn test_roundtrip_conversions() {
 }
 
 #[test]
-fn test_to_array_basic() {
-    let result = to_array("a,b,c");
-    assert_eq!(result, vec!["a", "b", "c"]);
+fn test_is_ascii_bytes() {
+    assert!(is_ascii_bytes(b"Hello"));
+    assert!(is_ascii_bytes(&[]));
+    assert!(is_ascii_bytes(&[0x00, 0x7F]));
+    assert!(!is_ascii_bytes(&[0x80]));
+    assert!(!is_ascii_bytes("🦀".as_bytes()));
 }
 
 #[test]
-fn test_to_array_with_spaces() {
-    let result = to_array(" a , b , c ");
-    assert_eq!(result, vec!["a", "b", "c"]);
+fn test_hex_to_bytes_with_prefix() {
+    assert_eq!(
+        hex_to_bytes("0xdeadbeef").unwrap(),
+        vec![0xDE, 0xAD, 0xBE, 0xEF]
+    );
+    assert_eq!(hex_to_bytes("0X00FF").unwrap(), vec![0x00, 0xFF]);
+    assert_eq!(hex_to_bytes("0x").unwrap(), Vec::<u8>::new());
+    assert_eq!(hex_to_bytes("").unwrap(), Vec::<u8>::new());
+    assert!(hex_to_bytes("0x0").is_err());
+    assert!(hex_to_bytes("0x0x00").is_err());
 }
 
 #[test]
-fn test_to_array_with_empty_elements() {
-    let result = to_array("a,,b,,c");
-    assert_eq!(result, vec!["a", "b", "c"]);
+fn test_bytes_to_binary_spaced() {
+    assert_eq!(bytes_to_binary_spaced(&[0xDE, 0xAD], " "), "11011110 10101101");
+    assert_eq!(bytes_to_binary_spaced(&[0x01, 0x80, 0xFF], ":"), "00000001:10000000:11111111");
+    assert_eq!(bytes_to_binary_spaced(&[0x05], " "), "00000101");
+    assert_eq!(bytes_to_binary_spaced(&[], " "), "");
+    assert_eq!(bytes_to_binary_spaced(&[0x00, 0x00], ""), "0000000000000000");
 }
 
 #[test]
-fn test_to_array_with_empty_string() {
-    let result = to_array("");
-    assert_eq!(result, Vec::<String>::new());
+fn test_bytes_to_hex_grouped() {
+    let bytes = &[0xDE, 0xAD, 0xBE, 0xEF, 0x01];
+    assert_eq!(bytes_to_hex_grouped(bytes, 1, ":"), "de:ad:be:ef:01");
+    assert_eq!(bytes_to_hex_grouped(bytes, 2, " "), "dead beef 01");
+    assert_eq!(bytes_to_hex_grouped(bytes, 8, " "), "deadbeef01");
+    assert_eq!(bytes_to_hex_grouped(bytes, 0, ":"), bytes_to_hex(bytes));
+    assert_eq!(bytes_to_hex_grouped(&[], 2, " "), "");
 }
 
 #[test]
-fn test_to_array_with_only_commas() {
-    let result = to_array(",,,");
-    assert_eq!(result, Vec::<String>::new());
+fn test_bytes_to_binary() {
+    assert_eq!(bytes_to_binary(&[5]), "00000101");
+    assert_eq!(bytes_to_binary(&[0x80, 0xFF]), "1000000011111111");
+    assert_eq!(bytes_to_binary(&[]), "");
 }
 
 #[test]
-fn test_to_array_with_whitespace_only_elements() {
-    let result = to_array("a, , ,b,  ,c");
-    assert_eq!(result, vec!["a", "b", "c"]);
+fn test_binary_to_bytes() {
+    assert_eq!(binary_to_bytes("00000101").unwrap(), vec![5]);
+    assert_eq!(binary_to_bytes("").unwrap(), Vec::<u8>::new());
+    assert_eq!(
+        binary_to_bytes("0000010"),
+        Err(ByteUtilsError::InvalidBinaryLength { len: 7 })
+    );
+    assert_eq!(
+        binary_to_bytes("0000000012000000"),
+        Err(ByteUtilsError::InvalidBinaryChar { index: 9, ch: '2' })
+    );
 }
 
 #[test]
-fn test_to_array_with_mixed_whitespace() {
-    let result = to_array("a,\tb\n,  c  ");
-    assert_eq!(result, vec!["a", "b", "c"]);
+fn test_binary_roundtrip() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    assert_eq!(binary_to_bytes(&bytes_to_binary(&bytes)).unwrap(), bytes);
 }
 
 #[test]
-fn test_to_array_single_element() {
-    let result = to_array("hello");
-    assert_eq!(result, vec!["hello"]);
+fn test_hex_to_bytes_lenient() {
+    assert_eq!(
+        hex_to_bytes_lenient("de ad be ef", &[]).unwrap(),
+        vec![0xDE, 0xAD, 0xBE, 0xEF]
+    );
+    assert_eq!(
+        hex_to_bytes_lenient("de:ad:be:ef", &[':']).unwrap(),
+        vec![0xDE, 0xAD, 0xBE, 0xEF]
+    );
+    assert_eq!(
+        hex_to_bytes_lenient("00-ff\n10\t20", &['-']).unwrap(),
+        vec![0x00, 0xFF, 0x10, 0x20]
+    );
+    assert_eq!(hex_to_bytes_lenient("  ", &[]).unwrap(), Vec::<u8>::new());
 }
 
 #[test]
-fn test_to_array_preserves_inner_spaces() {
-    let result = to_array("hello world,foo bar,baz");
-    assert_eq!(result, vec!["hello world", "foo bar", "baz"]);
+fn test_hex_to_bytes_lenient_errors() {
+    assert!(hex_to_bytes_lenient("de ad b", &[]).is_err());
+    assert!(hex_to_bytes_lenient("de:ad", &[]).is_err());
+    assert!(hex_to_bytes_lenient("zz yy", &[]).is_err());
 }
 
 #[test]
-fn test_to_array_with_special_characters() {
-    let result = to_array("hello!,@world,#rust$");
-    assert_eq!(result, vec!["hello!", "@world", "#rust$"]);
+fn test_set_bit_positions() {
+    assert_eq!(set_bit_positions(0b1000_0101), vec![0, 2, 7]);
+    assert_eq!(set_bit_positions(0xFF), vec![0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(set_bit_positions(0), Vec::<u8>::new());
 }
 
 #[test]
-fn test_escape_sql_basic() {
-    assert_eq!(escape_sql("normal text"), "normal text");
+fn test_set_bit_positions_slice() {
+    assert_eq!(set_bit_positions_slice(&[0x01, 0x80]), vec![0, 15]);
+    assert_eq!(set_bit_positions_slice(&[0x00, 0x03, 0x00]), vec![8, 9]);
+    assert_eq!(set_bit_positions_slice(&[]), Vec::<usize>::new());
 }
 
 #[test]
-fn test_escape_sql_with_single_quotes() {
-    assert_eq!(escape_sql("O'Connor"), "O''Connor");
+fn test_hex_to_bytes_error_variants() {
+    assert_eq!(hex_to_bytes("abc"), Err(ByteUtilsError::OddLength));
+    assert_eq!(
+        hex_to_bytes("0g"),
+        Err(ByteUtilsError::InvalidHexChar { index: 1, ch: 'g' })
+    );
+    assert_eq!(
+        hex_to_bytes("0x00zz"),
+        Err(ByteUtilsError::InvalidHexChar { index: 4, ch: 'z' })
+    );
+    assert_eq!(
+        hex_to_bytes("+f"),
+        Err(ByteUtilsError::InvalidHexChar { index: 0, ch: '+' })
+    );
+    assert_eq!(
+        hex_to_bytes_lenient("00 :g0", &[':']),
+        Err(ByteUtilsError::InvalidHexChar { index: 4, ch: 'g' })
+    );
+    assert_eq!(hex_to_bytes_lenient("0 0 0", &[]), Err(ByteUtilsError::OddLength));
 }
 
 #[test]
-fn test_escape_sql_with_backslashes() {
-    assert_eq!(escape_sql("C:\\path"), "C:\\\\path");
+fn test_utf8_error_variants() {
+    assert_eq!(
+        bytes_to_string(&[b'a', b'b', 0xFF]),
+        Err(ByteUtilsError::InvalidUtf8 {
+            valid_up_to: 2,
+            error_len: Some(1)
+        })
+    );
+    assert_eq!(
+        hex_to_string("ff"),
+        Err(ByteUtilsError::InvalidUtf8 {
+            valid_up_to: 0,
+            error_len: Some(1)
+        })
+    );
+    // A truncated multi-byte character at the end has no error length.
+    assert_eq!(
+        bytes_to_string(&[b'a', 0xF0, 0x9F]),
+        Err(ByteUtilsError::InvalidUtf8 {
+            valid_up_to: 1,
+            error_len: None
+        })
+    );
 }
 
 #[test]
-fn test_escape_sql_with_both() {
-    assert_eq!(escape_sql("O'Connor\\path"), "O''Connor\\\\path");
+fn test_utf8_error_recovers_prefix() {
+    let bytes = [b'o', b'k', 0xFF, 0xFE, b'!'];
+    match bytes_to_string(&bytes) {
+        Err(ByteUtilsError::InvalidUtf8 {
+            valid_up_to,
+            error_len: Some(len),
+        }) => {
+            assert_eq!(bytes_to_string(&bytes[..valid_up_to]).unwrap(), "ok");
+            assert_eq!(len, 1);
+            assert_eq!(valid_up_to + len, 3);
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
 }
 
 #[test]
-fn test_escape_sql_empty_string() {
-    assert_eq!(escape_sql(""), "");
+fn test_error_display() {
+    assert_eq!(
+        ByteUtilsError::OddLength.to_string(),
+        "Hex string must have an even number of characters"
+    );
+    assert_eq!(
+        ByteUtilsError::InvalidHexChar { index: 3, ch: 'z' }.to_string(),
+        "Invalid hex string: invalid character 'z' at index 3"
+    );
+    assert_eq!(
+        ByteUtilsError::InvalidUtf8 {
+            valid_up_to: 2,
+            error_len: Some(1)
+        }
+        .to_string(),
+        "Invalid UTF-8 sequence: valid up to index 2"
+    );
 }
 
 #[test]
-fn test_enclose_quotes_basic() {
-    assert_eq!(enclose_quotes("name"), "'name'");
+fn test_bitmask_from_positions() {
+    assert_eq!(bitmask_from_positions(&[0, 2, 7]).unwrap(), 0b1000_0101);
+    assert_eq!(bitmask_from_positions(&[]).unwrap(), 0);
+    assert_eq!(bitmask_from_positions(&[3, 3]).unwrap(), 0b0000_1000);
+    assert_eq!(
+        bitmask_from_positions(&[1, 8]),
+        Err(ByteUtilsError::InvalidBitPosition { position: 8 })
+    );
 }
 
 #[test]
-fn test_enclose_quotes_empty_string() {
-    assert_eq!(enclose_quotes(""), "''");
+fn test_bitmask_roundtrip() {
+    for byte in 0..=255u8 {
+        assert_eq!(bitmask_from_positions(&set_bit_positions(byte)).unwrap(), byte);
+    }
 }
 
 #[test]
-fn test_enclose_quotes_with_spaces() {
-    assert_eq!(enclose_quotes("table name"), "'table name'");
+fn test_bytes_to_base64() {
+    assert_eq!(bytes_to_base64(b""), "");
+    assert_eq!(bytes_to_base64(b"f"), "Zg==");
+    assert_eq!(bytes_to_base64(b"fo"), "Zm8=");
+    assert_eq!(bytes_to_base64(b"foo"), "Zm9v");
+    assert_eq!(bytes_to_base64(b"foob"), "Zm9vYg==");
+    assert_eq!(bytes_to_base64(b"fooba"), "Zm9vYmE=");
+    assert_eq!(bytes_to_base64(b"foobar"), "Zm9vYmFy");
+    assert_eq!(bytes_to_base64(&[0xFB, 0xFF]), "+/8=");
 }
 
 #[test]
-fn test_is_contain_word() {
-    assert!(is_contain_word("Hello world", "world"));
-    assert!(is_contain_word("The quick brown fox", "quick"));
-    assert!(!is_contain_word("Hello world", "worl"));
-    assert!(!is_contain_word("HelloWorld", "World"));
+fn test_base64_to_bytes() {
+    assert_eq!(base64_to_bytes("").unwrap(), b"");
+    assert_eq!(base64_to_bytes("Zg==").unwrap(), b"f");
+    assert_eq!(base64_to_bytes("Zm8=").unwrap(), b"fo");
+    assert_eq!(base64_to_bytes("Zm9vYmFy").unwrap(), b"foobar");
+    assert_eq!(base64_to_bytes("+/8=").unwrap(), vec![0xFB, 0xFF]);
 }
 
 #[test]
-fn test_has_contain_words() {
-    let words = vec![
-        "apple".to_string(),
-        "banana".to_string(),
-        "cherry".to_string(),
-    ];
-    assert!(has_contain_words("I like apple pie", &words));
-    assert!(has_contain_words("Banana split is delicious", &words));
-    assert!(!has_contain_words("I love peaches and pears", &words));
+fn test_base64_to_bytes_errors() {
+    assert_eq!(base64_to_bytes("Zg="), Err(ByteUtilsError::InvalidPadding));
+    assert_eq!(base64_to_bytes("Z==="), Err(ByteUtilsError::InvalidPadding));
+    assert_eq!(base64_to_bytes("Zm9vY==="), Err(ByteUtilsError::InvalidPadding));
+    assert_eq!(
+        base64_to_bytes("Zm=v"),
+        Err(ByteUtilsError::InvalidBase64Char { index: 2, ch: '=' })
+    );
+    assert_eq!(
+        base64_to_bytes("-_8="),
+        Err(ByteUtilsError::InvalidBase64Char { index: 0, ch: '-' })
+    );
 }
 
 #[test]
-fn test_basic_replacement() {
-    let input = "Hello {{name}}!";
-    let result = replace_placeholder(input, "name", "World");
-    assert_eq!(result, "Hello World!");
+fn test_base64_url() {
+    assert_eq!(bytes_to_base64_url(&[0xFB, 0xFF]), "-_8");
+    assert_eq!(bytes_to_base64_url(b"foob"), "Zm9vYg");
+    assert_eq!(base64_url_to_bytes("-_8").unwrap(), vec![0xFB, 0xFF]);
+    assert_eq!(base64_url_to_bytes("Zm9vYg").unwrap(), b"foob");
+    assert!(base64_url_to_bytes("Zm9vYg==").is_err());
+    assert!(base64_url_to_bytes("+/8").is_err());
+    assert_eq!(base64_url_to_bytes("Zm9vY"), Err(ByteUtilsError::InvalidPadding));
 }
 
 #[test]
-fn test_multiple_occurrences() {
-    let input = "{{greeting}} {{name}}! How are you, {{name}}?";
-    let result = replace_placeholder(input, "name", "Alice");
-    assert_eq!(result, "{{greeting}} Alice! How are you, Alice?");
+fn test_base64_roundtrip() {
+    for len in 0..64 {
+        let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 11) as u8).collect();
+        assert_eq!(base64_to_bytes(&bytes_to_base64(&bytes)).unwrap(), bytes);
+        assert_eq!(base64_url_to_bytes(&bytes_to_base64_url(&bytes)).unwrap(), bytes);
+    }
 }
 
 #[test]
-fn test_no_placeholder_present() {
-    let input = "Hello World!";
-    let result = replace_placeholder(input, "name", "Alice");
-    assert_eq!(result, "Hello World!");
+fn test_bytes_to_base32() {
+    assert_eq!(bytes_to_base32(b""), "");
+    assert_eq!(bytes_to_base32(b"f"), "MY======");
+    assert_eq!(bytes_to_base32(b"fo"), "MZXQ====");
+    assert_eq!(bytes_to_base32(b"foo"), "MZXW6===");
+    assert_eq!(bytes_to_base32(b"foob"), "MZXW6YQ=");
+    assert_eq!(bytes_to_base32(b"fooba"), "MZXW6YTB");
+    assert_eq!(bytes_to_base32(b"foobar"), "MZXW6YTBOI======");
+}
+
+#[test]
+fn test_base32_to_bytes() {
+    assert_eq!(base32_to_bytes("").unwrap(), b"");
+    assert_eq!(base32_to_bytes("MY======").unwrap(), b"f");
+    assert_eq!(base32_to_bytes("MZXW6YQ=").unwrap(), b"foob");
+    assert_eq!(base32_to_bytes("MZXW6YTBOI======").unwrap(), b"foobar");
+    assert_eq!(base32_to_bytes("mzxw6ytboi======").unwrap(), b"foobar");
+}
+
+#[test]
+fn test_base32_to_bytes_errors() {
+    assert_eq!(base32_to_bytes("MY====="), Err(ByteUtilsError::InvalidPadding));
+    assert_eq!(base32_to_bytes("M======="), Err(ByteUtilsError::InvalidPadding));
+    assert_eq!(base32_to_bytes("MZX====="), Err(ByteUtilsError::InvalidPadding));
+    for ch in ['0', '1', '8'] {
+        let input = format!("MZXW6YT{}", ch);
+        assert_eq!(
+            base32_to_bytes(&input),
+            Err(ByteUtilsError::InvalidBase32Char { index: 7, ch })
+        );
+    }
+}
+
+#[test]
+fn test_base32_roundtrip() {
+    let mut state: u32 = 0x1234_5678;
+    for len in 0..=10 {
+        for _ in 0..20 {
+            let bytes: Vec<u8> = (0..len)
+                .map(|_| {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    (state >> 16) as u8
+                })
+                .collect();
+            let encoded = bytes_to_base32(&bytes);
+            assert_eq!(base32_to_bytes(&encoded).unwrap(), bytes);
+            assert_eq!(base32_to_bytes(&encoded.to_lowercase()).unwrap(), bytes);
+        }
+    }
+}
+
+#[test]
+fn test_bytes_to_base58() {
+    assert_eq!(bytes_to_base58(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+    assert_eq!(bytes_to_base58(b"The quick brown fox jumps over the lazy dog."), "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z");
+    assert_eq!(bytes_to_base58(&[0x00, 0x00, 0x28, 0x7F, 0xB4, 0xCD]), "11233QC4");
+    assert_eq!(bytes_to_base58(&[0x00]), "1");
+    assert_eq!(bytes_to_base58(&[]), "");
+}
+
+#[test]
+fn test_base58_to_bytes() {
+    assert_eq!(base58_to_bytes("2NEpo7TZRRrLZSi2U").unwrap(), b"Hello World!");
+    assert_eq!(base58_to_bytes("11233QC4").unwrap(), vec![0x00, 0x00, 0x28, 0x7F, 0xB4, 0xCD]);
+    assert_eq!(base58_to_bytes("111").unwrap(), vec![0, 0, 0]);
+    assert_eq!(base58_to_bytes("").unwrap(), Vec::<u8>::new());
+}
+
+#[test]
+fn test_base58_to_bytes_invalid_char() {
+    assert_eq!(base58_to_bytes("12O3"), Err(ByteUtilsError::InvalidBase58Char { index: 2, ch: 'O' }));
+    assert_eq!(base58_to_bytes("0"), Err(ByteUtilsError::InvalidBase58Char { index: 0, ch: '0' }));
+    assert_eq!(base58_to_bytes("1l"), Err(ByteUtilsError::InvalidBase58Char { index: 1, ch: 'l' }));
+    assert_eq!(base58_to_bytes("ab🦀"), Err(ByteUtilsError::InvalidBase58Char { index: 2, ch: '🦀' }));
+}
+
+#[test]
+fn test_base58_roundtrip() {
+    for input in [&b""[..], &[0, 0, 0, 1], &[0xFF; 32], b"byteutils", &[0, 0xFF, 0]] {
+        assert_eq!(base58_to_bytes(&bytes_to_base58(input)).unwrap(), input);
+    }
+}
+
+#[test]
+fn test_percent_encode() {
+    assert_eq!(percent_encode("a b&c=é"), "a%20b%26c%3D%C3%A9");
+    assert_eq!(percent_encode("AZaz09-._~"), "AZaz09-._~");
+    assert_eq!(percent_encode("/?#[]@!$'()*+,;%"), "%2F%3F%23%5B%5D%40%21%24%27%28%29%2A%2B%2C%3B%25");
+    assert_eq!(percent_encode("🦀"), "%F0%9F%A6%80");
+    assert_eq!(percent_encode(""), "");
+}
+
+#[test]
+fn test_percent_decode() {
+    assert_eq!(percent_decode("a%20b%26c%3D%C3%A9").unwrap(), "a b&c=é");
+    assert_eq!(percent_decode("%f0%9f%a6%80").unwrap(), "🦀");
+    assert_eq!(percent_decode("a+b").unwrap(), "a+b");
+    assert_eq!(percent_decode("plain é").unwrap(), "plain é");
+    assert_eq!(percent_decode("").unwrap(), "");
+}
+
+#[test]
+fn test_percent_decode_errors() {
+    assert_eq!(percent_decode("100%"), Err(ByteUtilsError::InvalidPercentEscape { index: 3 }));
+    assert_eq!(percent_decode("%4"), Err(ByteUtilsError::InvalidPercentEscape { index: 0 }));
+    assert_eq!(percent_decode("ab%zz"), Err(ByteUtilsError::InvalidPercentEscape { index: 2 }));
+    assert_eq!(percent_decode("%0x12"), Err(ByteUtilsError::InvalidPercentEscape { index: 0 }));
+    assert_eq!(
+        percent_decode("ok%FF"),
+        Err(ByteUtilsError::InvalidUtf8 {
+            valid_up_to: 2,
+            error_len: Some(1)
+        })
+    );
+}
+
+#[test]
+fn test_percent_roundtrip() {
+    for input in ["hello world", "key=value&other=1", "日本語 🦀", "%%", ""] {
+        assert_eq!(percent_decode(&percent_encode(input)).unwrap(), input);
+    }
+}
+
+#[test]
+fn test_xor_bytes() {
+    assert_eq!(
+        xor_bytes(&[0x0F, 0xF0, 0xAA], &[0xFF, 0xFF, 0xAA]).unwrap(),
+        vec![0xF0, 0x0F, 0x00]
+    );
+    assert_eq!(xor_bytes(&[], &[]).unwrap(), Vec::<u8>::new());
+    assert_eq!(
+        xor_bytes(&[1, 2, 3], &[1, 2]),
+        Err(ByteUtilsError::InvalidLength { expected: 3, actual: 2 })
+    );
+}
+
+#[test]
+fn test_xor_bytes_repeating() {
+    assert_eq!(
+        xor_bytes_repeating(&[0x00, 0x00, 0x00, 0x00, 0x00], &[0x01, 0x02]).unwrap(),
+        vec![0x01, 0x02, 0x01, 0x02, 0x01]
+    );
+    let data = b"Burning 'em, if you ain't quick and nimble";
+    let encrypted = xor_bytes_repeating(data, b"ICE").unwrap();
+    assert_eq!(
+        bytes_to_hex(&encrypted),
+        "0b3637272a2b2e63622c2e69692a23693a2a3c6324202d623d63343c2a26226324272765272a282b2f20"
+    );
+    assert_eq!(xor_bytes_repeating(&encrypted, b"ICE").unwrap(), data);
+    assert_eq!(xor_bytes_repeating(&[], b"key").unwrap(), Vec::<u8>::new());
+    assert_eq!(xor_bytes_repeating(b"data", &[]), Err(ByteUtilsError::EmptyKey));
+}
+
+#[test]
+fn test_concat_bytes() {
+    assert_eq!(concat_bytes(&[b"ab", b"", b"cd", &[0x00]]), b"abcd\0");
+    assert_eq!(concat_bytes(&[]), Vec::<u8>::new());
+}
+
+#[test]
+fn test_split_bytes_on() {
+    assert_eq!(
+        split_bytes_on(b"a,b,c", b','),
+        vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()]
+    );
+    // Leading, consecutive and trailing delimiters produce empty pieces.
+    assert_eq!(
+        split_bytes_on(b",a,,b,", b','),
+        vec![vec![], b"a".to_vec(), vec![], b"b".to_vec(), vec![]]
+    );
+    assert_eq!(split_bytes_on(b"", b','), vec![Vec::<u8>::new()]);
+    assert_eq!(split_bytes_on(b"abc", b','), vec![b"abc".to_vec()]);
+    let text = "x,,y,";
+    let expected: Vec<Vec<u8>> = text.split(',').map(|s| s.as_bytes().to_vec()).collect();
+    assert_eq!(split_bytes_on(text.as_bytes(), b','), expected);
+}
+
+#[test]
+fn test_trim_zeros() {
+    assert_eq!(trim_trailing_zeros(&[0, 1, 0, 2, 0, 0]), &[0, 1, 0, 2]);
+    assert_eq!(trim_leading_zeros(&[0, 0, 1, 0, 2, 0]), &[1, 0, 2, 0]);
+    assert_eq!(trim_zeros(&[0, 0, 1, 0, 2, 0]), &[1, 0, 2]);
+    assert_eq!(trim_zeros(&[1, 2]), &[1, 2]);
+}
+
+#[test]
+fn test_trim_zeros_all_zero() {
+    assert!(trim_trailing_zeros(&[0, 0, 0]).is_empty());
+    assert!(trim_leading_zeros(&[0, 0, 0]).is_empty());
+    assert!(trim_zeros(&[0, 0, 0]).is_empty());
+    assert!(trim_zeros(&[]).is_empty());
+}
+
+#[test]
+fn test_crc32() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"The quick brown fox jumps over the lazy dog"), 0x414F_A339);
+    assert_eq!(bytes_to_hex(&crc32(b"123456789").to_be_bytes()), "cbf43926");
+}
+
+#[test]
+fn test_adler32() {
+    assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    assert_eq!(adler32(b""), 1);
+    assert_eq!(adler32(b"a"), 0x0062_0062);
+}
+
+#[test]
+fn test_adler32_long_input() {
+    // Long runs of 0xFF exercise the block reduction that prevents overflow.
+    let data = vec![0xFF; 100_000];
+    let mut a = 1u64;
+    let mut b = 0u64;
+    for &byte in &data {
+        a = (a + byte as u64) % 65521;
+        b = (b + a) % 65521;
+    }
+    assert_eq!(adler32(&data), ((b << 16) | a) as u32);
+}
+
+#[test]
+fn test_frame_with_crc32() {
+    let frame = frame_with_crc32(b"123456789");
+    assert_eq!(&frame[..9], b"123456789");
+    assert_eq!(&frame[9..], &[0xCB, 0xF4, 0x39, 0x26]);
+    assert_eq!(frame_with_crc32(b""), vec![0x00, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_verify_crc32_frame() {
+    let frame = frame_with_crc32(b"hello world");
+    assert_eq!(verify_crc32_frame(&frame).unwrap(), b"hello world");
+    assert_eq!(verify_crc32_frame(&[0, 0, 0, 0]).unwrap(), b"");
+}
+
+#[test]
+fn test_verify_crc32_frame_errors() {
+    assert_eq!(verify_crc32_frame(&[1, 2, 3]), Err(ByteUtilsError::FrameTooShort));
+
+    let mut frame = frame_with_crc32(b"123456789");
+    frame[0] = b'0';
+    assert!(matches!(
+        verify_crc32_frame(&frame),
+        Err(ByteUtilsError::ChecksumMismatch { expected: 0xCBF4_3926, .. })
+    ));
+}
+
+#[test]
+fn test_luhn_valid() {
+    assert!(luhn_valid("79927398713"));
+    assert!(luhn_valid("4539 1488 0343 6467"));
+    assert!(luhn_valid("4539-1488-0343-6467"));
+    assert!(!luhn_valid("79927398710"));
+    assert!(!luhn_valid("7992739871a"));
+    assert!(!luhn_valid("0"));
+    assert!(!luhn_valid(""));
+    assert!(!luhn_valid(" - "));
+}
+
+#[test]
+fn test_luhn_check_digit() {
+    assert_eq!(luhn_check_digit("7992739871").unwrap(), 3);
+    assert_eq!(luhn_check_digit("4539 1488 0343 646").unwrap(), 7);
+    assert_eq!(luhn_check_digit(""), Err(ByteUtilsError::EmptyInput));
+    assert_eq!(luhn_check_digit(" - "), Err(ByteUtilsError::EmptyInput));
+    assert_eq!(luhn_check_digit("0").unwrap(), 0);
+    assert_eq!(luhn_check_digit("12x4"), Err(ByteUtilsError::InvalidDigit { index: 2, ch: 'x' }));
+    for payload in ["1", "12", "123456", "000000"] {
+        let check = luhn_check_digit(payload).unwrap();
+        assert!(luhn_valid(&format!("{}{}", payload, check)));
+    }
+}
+
+#[test]
+fn test_write_hex() {
+    let mut out = Vec::new();
+    write_hex(&[0xDE, 0xAD, 0xBE, 0xEF], &mut out).unwrap();
+    assert_eq!(out, b"deadbeef");
+
+    let mut out = Vec::new();
+    write_hex(&[], &mut out).unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_write_hex_matches_bytes_to_hex() {
+    let bytes: Vec<u8> = (0..2000).map(|i| (i % 256) as u8).collect();
+    let mut out = Vec::new();
+    write_hex(&bytes, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), bytes_to_hex(&bytes));
+}
+
+#[test]
+fn test_hex_chars() {
+    assert_eq!(hex_chars(&[0xDE, 0xAD, 0x00, 0x0F]).collect::<String>(), "dead000f");
+    assert_eq!(hex_chars(&[]).count(), 0);
+    assert_eq!(hex_chars(&[1, 2, 3]).count(), 6);
+
+    let bytes: Vec<u8> = (0..=255).collect();
+    assert_eq!(hex_chars(&bytes).collect::<String>(), bytes_to_hex(&bytes));
+}
+
+#[test]
+fn test_hex_chars_into_fixed_buffer() {
+    let mut buf = ['\0'; 4];
+    for (slot, ch) in buf.iter_mut().zip(hex_chars(&[0xBE, 0xEF])) {
+        *slot = ch;
+    }
+    assert_eq!(buf, ['b', 'e', 'e', 'f']);
+}
+
+#[test]
+fn test_hex_to_bytes_into() {
+    let mut buf = Vec::new();
+    hex_to_bytes_into("dead", &mut buf).unwrap();
+    hex_to_bytes_into("0xbeef", &mut buf).unwrap();
+    hex_to_bytes_into("", &mut buf).unwrap();
+    assert_eq!(buf, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+}
+
+#[test]
+fn test_hex_to_bytes_into_leaves_buffer_on_error() {
+    let mut buf = vec![0x01, 0x02];
+    assert_eq!(
+        hex_to_bytes_into("aabbzz", &mut buf),
+        Err(ByteUtilsError::InvalidHexChar { index: 4, ch: 'z' })
+    );
+    assert_eq!(buf, vec![0x01, 0x02]);
+
+    assert_eq!(hex_to_bytes_into("aab", &mut buf), Err(ByteUtilsError::OddLength));
+    assert_eq!(buf, vec![0x01, 0x02]);
+}
+
+#[test]
+fn test_hex_eq_constant_time() {
+    assert!(hex_eq_constant_time("deadbeef", "deadbeef"));
+    assert!(hex_eq_constant_time("DEADBEEF", "0xdeadbeef"));
+    assert!(hex_eq_constant_time("", ""));
+    assert!(!hex_eq_constant_time("deadbeef", "deadbeee"));
+    assert!(!hex_eq_constant_time("00deadbeef", "deadbeef"));
+}
+
+#[test]
+fn test_hex_eq_constant_time_invalid() {
+    assert!(!hex_eq_constant_time("zz", "zz"));
+    assert!(!hex_eq_constant_time("abc", "abc"));
+    assert!(!hex_eq_constant_time("ab", "a"));
+}
+
+#[test]
+fn test_truncate_bytes_at_char_boundary() {
+    let bytes = "a🦀b".as_bytes();
+    assert_eq!(truncate_bytes_at_char_boundary(bytes, 0), b"");
+    assert_eq!(truncate_bytes_at_char_boundary(bytes, 1), b"a");
+    assert_eq!(truncate_bytes_at_char_boundary(bytes, 2), b"a");
+    assert_eq!(truncate_bytes_at_char_boundary(bytes, 4), b"a");
+    assert_eq!(truncate_bytes_at_char_boundary(bytes, 5), "a🦀".as_bytes());
+    assert_eq!(truncate_bytes_at_char_boundary(bytes, 6), bytes);
+    assert_eq!(truncate_bytes_at_char_boundary(bytes, 100), bytes);
+}
+
+#[test]
+fn test_truncate_bytes_at_char_boundary_always_valid() {
+    let text = "héllo, wörld! 日本語 🦀";
+    for max_len in 0..=text.len() {
+        let prefix = truncate_bytes_at_char_boundary(text.as_bytes(), max_len);
+        assert!(prefix.len() <= max_len);
+        assert!(std::str::from_utf8(prefix).is_ok());
+    }
+}
+
+#[test]
+fn test_hexdump() {
+    // Same lines as `printf 'The quick brown fox jumps\n' | hexdump -C`, which also
+    // prints a closing `0000001a` line that `hexdump` leaves out by design.
+    assert_eq!(
+        hexdump(b"The quick brown fox jumps\n"),
+        "00000000  54 68 65 20 71 75 69 63  6b 20 62 72 6f 77 6e 20  |The quick brown |\n\
+         00000010  66 6f 78 20 6a 75 6d 70  73 0a                    |fox jumps.|\n"
+    );
+    assert_eq!(hexdump(b""), "");
+}
+
+#[test]
+fn test_hexdump_width() {
+    assert_eq!(
+        hexdump_width(b"Hello!", 4),
+        "00000000  48 65 6c 6c  |Hell|\n00000004  6f 21        |o!|\n"
+    );
+    assert_eq!(hexdump_width(b"", 8), "");
+    assert_eq!(
+        hexdump_width(&[0x00, 0x41, 0x7F], 8),
+        "00000000  00 41 7f                 |.A.|\n"
+    );
+}
+
+#[test]
+fn test_hexdump_width_groups_of_eight() {
+    let bytes: Vec<u8> = (0x30..0x40).collect();
+    assert_eq!(
+        hexdump_width(&bytes, 16),
+        "00000000  30 31 32 33 34 35 36 37  38 39 3a 3b 3c 3d 3e 3f  |0123456789:;<=>?|\n"
+    );
+    assert_eq!(
+        hexdump_width(&bytes[..10], 16),
+        "00000000  30 31 32 33 34 35 36 37  38 39                    |0123456789|\n"
+    );
+}
+
+#[test]
+#[should_panic(expected = "bytes_per_line must be greater than 0")]
+fn test_hexdump_width_zero() {
+    hexdump_width(b"abc", 0);
+}
+
+#[test]
+fn test_identify_hash() {
+    assert_eq!(identify_hash("d41d8cd98f00b204e9800998ecf8427e"), Some("MD5"));
+    assert_eq!(identify_hash("da39a3ee5e6b4b0d3255bfef95601890afd80709"), Some("SHA-1"));
+    assert_eq!(identify_hash(&"ab".repeat(32)), Some("SHA-256"));
+    assert_eq!(identify_hash(&"AB".repeat(64)), Some("SHA-512"));
+}
+
+#[test]
+fn test_identify_hash_unknown() {
+    assert_eq!(identify_hash(""), None);
+    assert_eq!(identify_hash("abcd"), None);
+    assert_eq!(identify_hash(&"zz".repeat(16)), None);
+    assert_eq!(identify_hash(&"a".repeat(31)), None);
+}
+
+#[test]
+fn test_overlapping_chunks() {
+    let data = [1, 2, 3, 4, 5, 6];
+    assert_eq!(
+        overlapping_chunks(&data, 4, 2).unwrap(),
+        vec![&[1, 2, 3, 4][..], &[3, 4, 5, 6][..]]
+    );
+    assert_eq!(
+        overlapping_chunks(&data, 2, 2).unwrap(),
+        vec![&[1, 2][..], &[3, 4][..], &[5, 6][..]]
+    );
+    assert_eq!(overlapping_chunks(&data, 2, 3).unwrap(), vec![&[1, 2][..], &[4, 5][..]]);
+    assert_eq!(overlapping_chunks(&data, 7, 1).unwrap(), Vec::<&[u8]>::new());
+    assert_eq!(overlapping_chunks(&[], 1, 1).unwrap(), Vec::<&[u8]>::new());
+}
+
+#[test]
+fn test_overlapping_chunks_zero() {
+    assert_eq!(
+        overlapping_chunks(&[1, 2, 3], 2, 0),
+        Err(ByteUtilsError::InvalidChunkSize { size: 2, step: 0 })
+    );
+    assert_eq!(
+        overlapping_chunks(&[1, 2, 3], 0, 1),
+        Err(ByteUtilsError::InvalidChunkSize { size: 0, step: 1 })
+    );
+}
+
+#[test]
+fn test_line_buffer() {
+    let mut lines = LineBuffer::new();
+    lines.push(b"first\nsecond\r\nthi");
+    assert_eq!(lines.next_line(), Some(b"first".to_vec()));
+    assert_eq!(lines.next_line(), Some(b"second".to_vec()));
+    assert_eq!(lines.next_line(), None);
+    assert_eq!(lines.remaining(), b"thi");
+
+    lines.push(b"rd\n\n");
+    assert_eq!(lines.next_line(), Some(b"third".to_vec()));
+    assert_eq!(lines.next_line(), Some(b"".to_vec()));
+    assert_eq!(lines.next_line(), None);
+    assert!(lines.remaining().is_empty());
+}
+
+#[test]
+fn test_line_buffer_split_crlf() {
+    let mut lines = LineBuffer::default();
+    lines.push(b"abc\r");
+    assert_eq!(lines.next_line(), None);
+    lines.push(b"\n");
+    assert_eq!(lines.next_line(), Some(b"abc".to_vec()));
+    lines.push(b"a\rb\n");
+    assert_eq!(lines.next_line(), Some(b"a\rb".to_vec()));
+}
+
+#[test]
+fn test_line_buffer_interleaved() {
+    let mut lines = LineBuffer::new();
+    let mut seen = Vec::new();
+    for i in 0..1000 {
+        lines.push(format!("line {}\nline", i).as_bytes());
+        lines.push(b" tail");
+        lines.push(b"\n");
+        while let Some(line) = lines.next_line() {
+            seen.push(line);
+        }
+        assert!(lines.remaining().is_empty());
+    }
+    assert_eq!(seen.len(), 2000);
+    assert_eq!(seen[1998], b"line 999".to_vec());
+    assert_eq!(seen[1999], b"line tail".to_vec());
+
+    lines.push(b"a\nb\nc");
+    assert_eq!(lines.next_line(), Some(b"a".to_vec()));
+    lines.push(b"d");
+    assert_eq!(lines.remaining(), b"b\ncd");
+    assert_eq!(lines.next_line(), Some(b"b".to_vec()));
+    assert_eq!(lines.next_line(), None);
+    lines.push(b"e\nf");
+    assert_eq!(lines.remaining(), b"cde\nf");
+    assert_eq!(lines.next_line(), Some(b"cde".to_vec()));
+    assert_eq!(lines.remaining(), b"f");
+}
+
+#[test]
+fn test_byte_delta_identical() {
+    let data = b"the same data on both sides";
+    assert_eq!(
+        byte_delta(data, data),
+        vec![DeltaOp::Copy { start: 0, len: data.len() }]
+    );
+}
+
+#[test]
+fn test_byte_delta_edge_cases() {
+    assert_eq!(byte_delta(b"", b""), vec![]);
+    assert_eq!(byte_delta(b"abc", b""), vec![]);
+    assert_eq!(byte_delta(b"", b"abc"), vec![DeltaOp::Insert(b"abc".to_vec())]);
+    assert_eq!(byte_delta(b"abc", b"abc"), vec![DeltaOp::Insert(b"abc".to_vec())]);
+}
+
+#[test]
+fn test_byte_delta_roundtrip() {
+    let cases: [(&[u8], &[u8]); 4] = [
+        (b"hello world, hello rust", b"hello rust, hello world!"),
+        (b"0123456789abcdef", b"xx0123yy89abcdefzz"),
+        (b"aaaaaaaaaaaaaaaa", b"aaaaaaaabaaaaaaaa"),
+        (&[0, 1, 2, 3, 4, 5, 6, 7], &[4, 5, 6, 7, 0, 1, 2, 3]),
+    ];
+    for (old, new) in cases {
+        assert_eq!(apply_delta(old, &byte_delta(old, new)), new);
+    }
+}
+
+#[test]
+fn test_byte_delta_uses_copies() {
+    let old: Vec<u8> = (0..=255).collect();
+    let mut new = old.clone();
+    new[100] = 0xFF;
+    let ops = byte_delta(&old, &new);
+    let inserted: usize = ops
+        .iter()
+        .map(|op| match op {
+            DeltaOp::Insert(bytes) => bytes.len(),
+            DeltaOp::Copy { .. } => 0,
+        })
+        .sum();
+    assert!(inserted <= 1);
+    assert_eq!(apply_delta(&old, &ops), new);
+}
+
+#[test]
+#[should_panic]
+fn test_apply_delta_out_of_range() {
+    apply_delta(b"abc", &[DeltaOp::Copy { start: 2, len: 5 }]);
+}
+
+#[test]
+fn test_collapse_whitespace_bytes() {
+    assert_eq!(collapse_whitespace_bytes(b"a  b\t\tc\r\nd"), b"a b c d");
+    assert_eq!(collapse_whitespace_bytes(b"  padded  "), b"padded");
+    assert_eq!(collapse_whitespace_bytes(b" \t\r\n "), b"");
+    assert_eq!(collapse_whitespace_bytes(b""), b"");
+}
+
+#[test]
+fn test_collapse_whitespace_bytes_non_utf8() {
+    assert_eq!(
+        collapse_whitespace_bytes(&[0xFF, b' ', b' ', 0xFE]),
+        vec![0xFF, b' ', 0xFE]
+    );
+}
+
+#[test]
+fn test_deinterleave() {
+    assert_eq!(deinterleave(&[1, 10, 2, 20, 3, 30], 2).unwrap(), vec![vec![1, 2, 3], vec![10, 20, 30]]);
+    assert_eq!(deinterleave(&[1, 2, 3], 1).unwrap(), vec![vec![1, 2, 3]]);
+    assert_eq!(deinterleave(&[], 3).unwrap(), vec![Vec::<u8>::new(); 3]);
+    assert_eq!(deinterleave(&[1, 2, 3], 2), Err(ByteUtilsError::InvalidChannelLayout { len: 3, channels: 2 }));
+    assert_eq!(deinterleave(&[1, 2], 0), Err(ByteUtilsError::InvalidChannelLayout { len: 2, channels: 0 }));
+}
+
+#[test]
+fn test_interleave_channels() {
+    assert_eq!(interleave_channels(&[vec![1, 2, 3], vec![10, 20, 30]]).unwrap(), vec![1, 10, 2, 20, 3, 30]);
+    assert_eq!(interleave_channels(&[]).unwrap(), Vec::<u8>::new());
+    assert_eq!(
+        interleave_channels(&[vec![1, 2], vec![3, 4], vec![5]]),
+        Err(ByteUtilsError::ChannelLengthMismatch { channel: 2, expected: 2, actual: 1 })
+    );
+}
+
+#[test]
+fn test_interleave_roundtrip() {
+    let data: Vec<u8> = (0..=255).collect();
+    for channels in [1, 2, 4, 8] {
+        assert_eq!(interleave_channels(&deinterleave(&data, channels).unwrap()).unwrap(), data);
+    }
+}
+
+#[test]
+fn test_bytes_to_float() {
+    assert_eq!(bytes_to_f32_be(&[0x3F, 0xC0, 0x00, 0x00]).unwrap(), 1.5);
+    assert_eq!(bytes_to_f32_le(&[0x00, 0x00, 0xC0, 0xBF]).unwrap(), -1.5);
+    assert_eq!(bytes_to_f64_be(&[0x40, 0x09, 0x21, 0xFB, 0x54, 0x44, 0x2D, 0x18]).unwrap(), std::f64::consts::PI);
+    assert_eq!(bytes_to_f64_le(&[0x18, 0x2D, 0x44, 0x54, 0xFB, 0x21, 0x09, 0x40]).unwrap(), std::f64::consts::PI);
+    assert!(bytes_to_f32_be(&[0x7F, 0xC0, 0x00, 0x00]).unwrap().is_nan());
+}
+
+#[test]
+fn test_bytes_to_float_wrong_length() {
+    assert_eq!(bytes_to_f32_be(&[0; 3]), Err(ByteUtilsError::InvalidLength { expected: 4, actual: 3 }));
+    assert_eq!(bytes_to_f32_le(&[0; 8]), Err(ByteUtilsError::InvalidLength { expected: 4, actual: 8 }));
+    assert_eq!(bytes_to_f64_be(&[0; 4]), Err(ByteUtilsError::InvalidLength { expected: 8, actual: 4 }));
+    assert_eq!(bytes_to_f64_le(&[]), Err(ByteUtilsError::InvalidLength { expected: 8, actual: 0 }));
+}
+
+#[test]
+fn test_float_roundtrip() {
+    for value in [0.0f32, -0.0, 1.5, f32::MAX, f32::MIN_POSITIVE, f32::INFINITY] {
+        assert_eq!(bytes_to_f32_be(&f32_to_bytes_be(value)).unwrap().to_bits(), value.to_bits());
+        assert_eq!(bytes_to_f32_le(&f32_to_bytes_le(value)).unwrap().to_bits(), value.to_bits());
+    }
+    for value in [0.0f64, -2.25, f64::MAX, f64::NEG_INFINITY] {
+        assert_eq!(bytes_to_f64_be(&f64_to_bytes_be(value)).unwrap().to_bits(), value.to_bits());
+        assert_eq!(bytes_to_f64_le(&f64_to_bytes_le(value)).unwrap().to_bits(), value.to_bits());
+    }
+}
+
+#[test]
+fn test_int_to_bytes() {
+    assert_eq!(u16_to_bytes(0x1234, true), [0x12, 0x34]);
+    assert_eq!(u16_to_bytes(0x1234, false), [0x34, 0x12]);
+    assert_eq!(u32_to_bytes(0xDEADBEEF, true), [0xDE, 0xAD, 0xBE, 0xEF]);
+    assert_eq!(u32_to_bytes(0xDEADBEEF, false), [0xEF, 0xBE, 0xAD, 0xDE]);
+    assert_eq!(u64_to_bytes(1, true), [0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(u64_to_bytes(1, false), [1, 0, 0, 0, 0, 0, 0, 0]);
+}
+
+#[test]
+fn test_bytes_to_int() {
+    assert_eq!(bytes_to_u16(&[0x12, 0x34], true).unwrap(), 0x1234);
+    assert_eq!(bytes_to_u16(&[0x12, 0x34], false).unwrap(), 0x3412);
+    assert_eq!(bytes_to_u32(&hex_to_bytes("deadbeef").unwrap(), true).unwrap(), 0xDEADBEEF);
+    assert_eq!(bytes_to_u32(&[0xEF, 0xBE, 0xAD, 0xDE], false).unwrap(), 0xDEADBEEF);
+    assert_eq!(bytes_to_u64(&u64_to_bytes(u64::MAX - 1, false), false).unwrap(), u64::MAX - 1);
+}
+
+#[test]
+fn test_bytes_to_int_wrong_length() {
+    assert_eq!(bytes_to_u16(&[1], true), Err(ByteUtilsError::InvalidLength { expected: 2, actual: 1 }));
+    assert_eq!(bytes_to_u32(&[1, 2, 3, 4, 5], false), Err(ByteUtilsError::InvalidLength { expected: 4, actual: 5 }));
+    assert_eq!(bytes_to_u64(&[], true), Err(ByteUtilsError::InvalidLength { expected: 8, actual: 0 }));
+}
+
+#[test]
+fn test_swap_endianness() {
+    let mut bytes = [0x01, 0x02, 0x03, 0x04];
+    swap_endianness(&mut bytes);
+    assert_eq!(bytes, [0x04, 0x03, 0x02, 0x01]);
+
+    let mut encoded = u16_to_bytes(0xABCD, false);
+    swap_endianness(&mut encoded);
+    assert_eq!(bytes_to_u16(&encoded, true).unwrap(), 0xABCD);
+
+    let mut empty: [u8; 0] = [];
+    swap_endianness(&mut empty);
+}
+
+#[test]
+fn test_to_array_basic() {
+    let result = to_array("a,b,c");
+    assert_eq!(result, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_to_array_with_spaces() {
+    let result = to_array(" a , b , c ");
+    assert_eq!(result, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_to_array_with_empty_elements() {
+    let result = to_array("a,,b,,c");
+    assert_eq!(result, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_to_array_with_empty_string() {
+    let result = to_array("");
+    assert_eq!(result, Vec::<String>::new());
+}
+
+#[test]
+fn test_to_array_with_only_commas() {
+    let result = to_array(",,,");
+    assert_eq!(result, Vec::<String>::new());
+}
+
+#[test]
+fn test_to_array_with_whitespace_only_elements() {
+    let result = to_array("a, , ,b,  ,c");
+    assert_eq!(result, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_to_array_with_mixed_whitespace() {
+    let result = to_array("a,\tb\n,  c  ");
+    assert_eq!(result, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_to_array_single_element() {
+    let result = to_array("hello");
+    assert_eq!(result, vec!["hello"]);
+}
+
+#[test]
+fn test_to_array_preserves_inner_spaces() {
+    let result = to_array("hello world,foo bar,baz");
+    assert_eq!(result, vec!["hello world", "foo bar", "baz"]);
+}
+
+#[test]
+fn test_to_array_with_special_characters() {
+    let result = to_array("hello!,@world,#rust$");
+    assert_eq!(result, vec!["hello!", "@world", "#rust$"]);
+}
+
+#[test]
+fn test_to_array_with_delimiter() {
+    assert_eq!(to_array_with_delimiter("a|b|c", '|'), vec!["a", "b", "c"]);
+    assert_eq!(to_array_with_delimiter(" a ; ; b ", ';'), vec!["a", "b"]);
+    assert_eq!(to_array_with_delimiter("a,b", '|'), vec!["a,b"]);
+    assert_eq!(to_array_with_delimiter("", '|'), Vec::<String>::new());
+}
+
+#[test]
+fn test_to_array_with_unicode_delimiter() {
+    assert_eq!(
+        to_array_with_delimiter("りんご、 みかん、、ぶどう", '、'),
+        vec!["りんご", "みかん", "ぶどう"]
+    );
+}
+
+#[test]
+fn test_to_array_keep_empty() {
+    assert_eq!(to_array_keep_empty("a,,c", ','), vec!["a", "", "c"]);
+    assert_eq!(to_array_keep_empty("a,b,", ','), vec!["a", "b", ""]);
+    assert_eq!(to_array_keep_empty(" a | | c ", '|'), vec!["a", "", "c"]);
+    assert_eq!(to_array_keep_empty("", ','), vec![""]);
+}
+
+#[test]
+fn test_join_strings() {
+    let parts = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    assert_eq!(join_strings(&parts, ","), "a,b,c");
+    assert_eq!(join_strings(&parts, " | "), "a | b | c");
+    assert_eq!(join_strings(&parts[..1], ","), "a");
+    assert_eq!(join_strings(&[], ","), "");
+}
+
+#[test]
+fn test_join_strings_roundtrip() {
+    let parts = to_array(" x , y,,z ");
+    assert_eq!(to_array(&join_strings(&parts, ",")), parts);
+}
+
+#[test]
+fn test_lines_to_vec() {
+    assert_eq!(lines_to_vec("a\nb\r\nc"), vec!["a", "b", "c"]);
+    assert_eq!(lines_to_vec("a\nb\n"), vec!["a", "b"]);
+    assert_eq!(lines_to_vec("a\n\n"), vec!["a", ""]);
+    assert_eq!(lines_to_vec("  indented \n"), vec!["  indented "]);
+    assert!(lines_to_vec("").is_empty());
+}
+
+#[test]
+fn test_vec_to_lines() {
+    let lines = vec!["a".to_string(), "".to_string(), "c".to_string()];
+    assert_eq!(vec_to_lines(&lines), "a\n\nc");
+    assert_eq!(vec_to_lines(&[]), "");
+    assert_eq!(vec_to_lines(&lines_to_vec("x\r\ny\n")), "x\ny");
+}
+
+#[test]
+fn test_escape_sql_basic() {
+    assert_eq!(escape_sql("normal text"), "normal text");
+}
+
+#[test]
+fn test_escape_sql_with_single_quotes() {
+    assert_eq!(escape_sql("O'Connor"), "O''Connor");
+}
+
+#[test]
+fn test_escape_sql_with_backslashes() {
+    assert_eq!(escape_sql("C:\\path"), "C:\\\\path");
+}
+
+#[test]
+fn test_escape_sql_with_both() {
+    assert_eq!(escape_sql("O'Connor\\path"), "O''Connor\\\\path");
+}
+
+#[test]
+fn test_escape_sql_empty_string() {
+    assert_eq!(escape_sql(""), "");
+}
+
+#[test]
+fn test_escape_sql_mysql_quotes() {
+    assert_eq!(escape_sql_mysql("O'Connor"), "O\\'Connor");
+    assert_eq!(escape_sql_mysql("say \"hi\""), "say \\\"hi\\\"");
+    assert_eq!(escape_sql_mysql("C:\\path"), "C:\\\\path");
+}
+
+#[test]
+fn test_escape_sql_mysql_control_chars() {
+    assert_eq!(escape_sql_mysql("a\0b"), "a\\0b");
+    assert_eq!(escape_sql_mysql("line1\nline2\r"), "line1\\nline2\\r");
+    assert_eq!(escape_sql_mysql("\x1A"), "\\Z");
+    assert_eq!(escape_sql_mysql(""), "");
+    assert_eq!(escape_sql_mysql("plain"), "plain");
+}
+
+#[test]
+fn test_enclose_quotes_basic() {
+    assert_eq!(enclose_quotes("name"), "'name'");
+}
+
+#[test]
+fn test_enclose_quotes_empty_string() {
+    assert_eq!(enclose_quotes(""), "''");
+}
+
+#[test]
+fn test_enclose_quotes_with_spaces() {
+    assert_eq!(enclose_quotes("table name"), "'table name'");
+}
+
+#[test]
+fn test_shell_quote() {
+    assert_eq!(shell_quote("O'Reilly"), r"'O'\''Reilly'");
+    assert_eq!(shell_quote(""), "''");
+    assert_eq!(shell_quote("plain"), "'plain'");
+    assert_eq!(shell_quote("a b\n$(cmd) `x` \"q\" \\"), "'a b\n$(cmd) `x` \"q\" \\'");
+    assert_eq!(shell_quote("''"), r"''\'''\'''");
+}
+
+#[test]
+fn test_escape_identifier() {
+    assert_eq!(escape_identifier("users"), "\"users\"");
+    assert_eq!(escape_identifier("my\"table"), "\"my\"\"table\"");
+    assert_eq!(escape_identifier("\"\""), "\"\"\"\"\"\"");
+    assert_eq!(escape_identifier(""), "\"\"");
+}
+
+#[test]
+fn test_escape_identifier_strips_nul() {
+    assert_eq!(escape_identifier("ta\0ble"), "\"table\"");
+}
+
+#[test]
+fn test_escape_html() {
+    assert_eq!(escape_html("<b>\"Tom\" & 'Jerry'</b>"), "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;");
+    assert_eq!(escape_html("&amp;"), "&amp;amp;");
+    assert_eq!(escape_html("plain text 🦀"), "plain text 🦀");
+    assert_eq!(escape_html(""), "");
+}
+
+#[test]
+fn test_unescape_html() {
+    assert_eq!(unescape_html("&lt;b&gt;&quot;hi&quot; &amp; &#39;bye&apos;"), "<b>\"hi\" & 'bye'");
+    assert_eq!(unescape_html("&#65;&#x42;&#X43;"), "ABC");
+    assert_eq!(unescape_html("&amp;amp;"), "&amp;");
+    assert_eq!(unescape_html("&copy; &nbsp &#xD800; &#; &#x; &#+65;"), "&copy; &nbsp &#xD800; &#; &#x; &#+65;");
+    assert_eq!(unescape_html("a & b;"), "a & b;");
+    assert_eq!(unescape_html("trailing &"), "trailing &");
+}
+
+#[test]
+fn test_escape_json() {
+    assert_eq!(escape_json(r#"a "quoted" \path"#), r#"a \"quoted\" \\path"#);
+    assert_eq!(escape_json("\n\r\t\x08\x0C"), r"\n\r\t\b\f");
+    assert_eq!(escape_json("\0\x01\x1F"), r"\u0000\u0001\u001f");
+    assert_eq!(escape_json(" ~\x7Fé🦀/"), " ~\x7Fé🦀/");
+    assert_eq!(escape_json(""), "");
+}
+
+#[test]
+fn test_html_roundtrip() {
+    for input in ["<script>alert('x')</script>", "a && b", "&lt; already", "quote \" mark", ""] {
+        assert_eq!(unescape_html(&escape_html(input)), input);
+    }
+}
+
+#[test]
+fn test_is_contain_word() {
+    assert!(is_contain_word("Hello world", "world"));
+    assert!(is_contain_word("The quick brown fox", "quick"));
+    assert!(!is_contain_word("Hello world", "worl"));
+    assert!(!is_contain_word("HelloWorld", "World"));
+}
+
+#[test]
+fn test_is_contain_word_boundaries() {
+    assert!(is_contain_word("WORLD!", "world"));
+    assert!(is_contain_word("(new york)", "New York"));
+    assert!(!is_contain_word("snake_case", "case"));
+    assert!(!is_contain_word("naïve", "na"));
+    assert!(is_contain_word("Über alles", "über"));
+    assert!(!is_contain_word("c++ code", "c++"));
+    assert!(is_contain_word("x c++y", "c++"));
+    assert!(!is_contain_word("", "word"));
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_is_contain_word_agrees_with_regex() {
+    let words = ["world", "c++", "new york", "Über", "_id", "", "a.b"];
+    let sources = ["Hello World", "c++ code", "x c++y", "New York!", "über", "my_id _id", "", "a.b a-b"];
+    for word in words {
+        let re = regex::Regex::new(&format!(r"(?i)\b{}\b", regex::escape(word))).unwrap();
+        for src in sources {
+            assert_eq!(is_contain_word(src, word), re.is_match(src), "{:?} in {:?}", word, src);
+        }
+    }
+}
+
+#[test]
+fn test_has_contain_words() {
+    let words = vec![
+        "apple".to_string(),
+        "banana".to_string(),
+        "cherry".to_string(),
+    ];
+    assert!(has_contain_words("I like apple pie", &words));
+    assert!(has_contain_words("Banana split is delicious", &words));
+    assert!(!has_contain_words("I love peaches and pears", &words));
+}
+
+#[test]
+fn test_find_contain_words() {
+    let words = vec![
+        "cherry".to_string(),
+        "apple".to_string(),
+        "pear".to_string(),
+        "apple".to_string(),
+    ];
+    assert_eq!(find_contain_words("Apple and cherry pie", &words), vec!["cherry", "apple"]);
+    assert_eq!(find_contain_words("pineapple pears", &words), Vec::<String>::new());
+    assert_eq!(find_contain_words("anything", &[]), Vec::<String>::new());
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_word_matcher() {
+    let words = vec![
+        "apple".to_string(),
+        "banana".to_string(),
+        "cherry".to_string(),
+        "apple".to_string(),
+    ];
+    let matcher = WordMatcher::new(&words).unwrap();
+    assert!(matcher.is_match("I like apple pie"));
+    assert!(matcher.is_match("Banana split is delicious"));
+    assert!(!matcher.is_match("I love peaches and pineapple"));
+    assert_eq!(matcher.matches("CHERRY and Apple"), vec!["apple", "cherry"]);
+    assert_eq!(matcher.matches("nothing here"), Vec::<String>::new());
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_word_matcher_agrees_with_find_contain_words() {
+    let words = vec!["new york".to_string(), "york".to_string(), "c++".to_string()];
+    let matcher = WordMatcher::new(&words).unwrap();
+    for src in ["I love New York", "york", "c++ code", "newyork"] {
+        assert_eq!(matcher.matches(src), find_contain_words(src, &words));
+        assert_eq!(matcher.is_match(src), has_contain_words(src, &words));
+    }
+}
+
+#[test]
+#[cfg(feature = "regex")]
+fn test_word_matcher_empty() {
+    assert!(matches!(WordMatcher::new(&[]), Err(ByteUtilsError::EmptyWordList)));
+}
+
+#[test]
+fn test_basic_replacement() {
+    let input = "Hello {{name}}!";
+    let result = replace_placeholder(input, "name", "World");
+    assert_eq!(result, "Hello World!");
+}
+
+#[test]
+fn test_multiple_occurrences() {
+    let input = "{{greeting}} {{name}}! How are you, {{name}}?";
+    let result = replace_placeholder(input, "name", "Alice");
+    assert_eq!(result, "{{greeting}} Alice! How are you, Alice?");
+}
+
+#[test]
+fn test_no_placeholder_present() {
+    let input = "Hello World!";
+    let result = replace_placeholder(input, "name", "Alice");
+    assert_eq!(result, "Hello World!");
+}
+
+#[test]
+fn test_special_characters_in_replacement() {
+    let input = "Hello {{name}}!";
+    let result = replace_placeholder(input, "name", "$&*");
+    assert_eq!(result, "Hello $&*!");
+}
+
+#[test]
+fn test_empty_replacement() {
+    let input = "Hello {{name}}!";
+    let result = replace_placeholder(input, "name", "");
+    assert_eq!(result, "Hello !");
+}
+
+#[test]
+fn test_placeholder_with_spaces() {
+    let input = "Hello {{user name}}!";
+    let result = replace_placeholder(input, "user name", "John Doe");
+    assert_eq!(result, "Hello John Doe!");
+}
+
+#[test]
+fn test_nested_placeholders() {
+    let input = "Hello {{outer{{inner}}}}!";
+    let result = replace_placeholder(input, "outer{{inner}}", "World");
+    assert_eq!(result, "Hello World!");
+}
+
+#[test]
+fn test_replace_placeholder_delimited() {
+    let input = "Hello <<name>>, {{name}} stays";
+    let result = replace_placeholder_delimited(input, "name", "World", "<<", ">>");
+    assert_eq!(result, "Hello World, {{name}} stays");
+}
+
+#[test]
+fn test_replace_placeholder_delimited_regex_chars() {
+    let result = replace_placeholder_delimited("cost: ${price}", "price", "10", "${", "}");
+    assert_eq!(result, "cost: 10");
+
+    let result = replace_placeholder_delimited("a (x) b (x)", "x", "y", "(", ")");
+    assert_eq!(result, "a y b y");
+
+    let result = replace_placeholder_delimited("[.*]", ".*", "ok", "[", "]");
+    assert_eq!(result, "ok");
+}
+
+#[test]
+fn test_replace_placeholder_literal_replacement() {
+    let result = replace_placeholder("price: {{amount}}", "amount", "$1 or ${x}");
+    assert_eq!(result, "price: $1 or ${x}");
+}
+
+fn create_test_map() -> HashMap<String, String> {
+    let mut map = HashMap::new();
+    map.insert("name".to_string(), "John".to_string());
+    map.insert("age".to_string(), "30".to_string());
+    map.insert("city".to_string(), "New York".to_string());
+    map
+}
+
+#[test]
+fn test_multiple_replacements() {
+    let template = "{{name}} is {{age}} years old and lives in {{city}}.";
+    let replacements = create_test_map();
+    let result = replace_multiple_placeholders(template, &replacements);
+    assert_eq!(result, "John is 30 years old and lives in New York.");
+}
+
+#[test]
+fn test_repeated_placeholders() {
+    let template = "{{name}} {{name}} {{name}}";
+    let replacements = create_test_map();
+    let result = replace_multiple_placeholders(template, &replacements);
+    assert_eq!(result, "John John John");
+}
+
+#[test]
+fn test_missing_placeholder() {
+    let template = "{{name}} is {{age}} years old and works as {{job}}.";
+    let replacements = create_test_map();
+    let result = replace_multiple_placeholders(template, &replacements);
+    assert_eq!(result, "John is 30 years old and works as {{job}}.");
+}
+
+#[test]
+fn test_empty_template() {
+    let template = "";
+    let replacements = create_test_map();
+    let result = replace_multiple_placeholders(template, &replacements);
+    assert_eq!(result, "");
+}
+
+#[test]
+fn test_empty_map() {
+    let template = "Hello {{name}}!";
+    let replacements = HashMap::new();
+    let result = replace_multiple_placeholders(template, &replacements);
+    assert_eq!(result, "Hello {{name}}!");
+}
+
+#[test]
+fn test_special_characters() {
+    let mut replacements = HashMap::new();
+    replacements.insert("special".to_string(), "$&*^%".to_string());
+    let template = "Special chars: {{special}}";
+    let result = replace_multiple_placeholders(template, &replacements);
+    assert_eq!(result, "Special chars: $&*^%");
+}
+
+#[test]
+fn test_nested_replacement() {
+    let mut replacements = HashMap::new();
+    replacements.insert("outer".to_string(), "{{inner}}".to_string());
+    replacements.insert("inner".to_string(), "value".to_string());
+    let template = "Nested: {{outer}}";
+    // One pass expands {{inner}} only if "inner" is visited after "outer"
+    let once = replace_multiple_placeholders(template, &replacements);
+    assert!(once == "Nested: value" || once == "Nested: {{inner}}");
+    // A second pass resolves it whatever the iteration order was
+    let result = replace_multiple_placeholders(&once, &replacements);
+    assert_eq!(result, "Nested: value");
+}
+
+#[test]
+fn test_unicode_characters() {
+    let mut replacements = HashMap::new();
+    replacements.insert("greeting".to_string(), "你好".to_string());
+    replacements.insert("name".to_string(), "José".to_string());
+    let template = "{{greeting}}, {{name}}!";
+    let result = replace_multiple_placeholders(template, &replacements);
+    assert_eq!(result, "你好, José!");
+}
+
+#[test]
+fn test_levenshtein() {
+    assert_eq!(levenshtein("kitten", "sitting"), 3);
+    assert_eq!(levenshtein("sitting", "kitten"), 3);
+    assert_eq!(levenshtein("flaw", "lawn"), 2);
+    assert_eq!(levenshtein("same", "same"), 0);
+    assert_eq!(levenshtein("", "abc"), 3);
+    assert_eq!(levenshtein("abc", ""), 3);
+    assert_eq!(levenshtein("", ""), 0);
+}
+
+#[test]
+fn test_levenshtein_unicode() {
+    assert_eq!(levenshtein("🦀🦀", "🦀"), 1);
+    assert_eq!(levenshtein("café", "cafe"), 1);
+    assert_eq!(levenshtein("日本語", "日本"), 1);
+}
+
+#[test]
+fn test_truncate_str() {
+    assert_eq!(truncate_str("🦀abc", 2), "");
+    assert_eq!(truncate_str("🦀abc", 4), "🦀");
+    assert_eq!(truncate_str("🦀abc", 5), "🦀a");
+    assert_eq!(truncate_str("héllo", 2), "h");
+    assert_eq!(truncate_str("abc", 3), "abc");
+    assert_eq!(truncate_str("abc", 0), "");
+    assert_eq!(truncate_str("", 5), "");
+}
+
+#[test]
+fn test_strip_prefix_ci() {
+    assert_eq!(strip_prefix_ci("BEARER abc", "bearer "), Some("abc"));
+    assert_eq!(strip_prefix_ci("Bearer ", "bearer "), Some(""));
+    assert_eq!(strip_prefix_ci("abc", ""), Some("abc"));
+    assert_eq!(strip_prefix_ci("Basic abc", "bearer "), None);
+    assert_eq!(strip_prefix_ci("bear", "bearer "), None);
+    // A prefix length that falls inside a multi-byte character never matches.
+    assert_eq!(strip_prefix_ci("🦀abc", "ab"), None);
+}
+
+#[test]
+fn test_strip_suffix_ci() {
+    assert_eq!(strip_suffix_ci("report.PDF", ".pdf"), Some("report"));
+    assert_eq!(strip_suffix_ci("abc", ""), Some("abc"));
+    assert_eq!(strip_suffix_ci("report.txt", ".pdf"), None);
+    assert_eq!(strip_suffix_ci("pdf", "report.pdf"), None);
+    assert_eq!(strip_suffix_ci("abc🦀", "ab"), None);
+}
+
+#[test]
+fn test_truncate_with_ellipsis() {
+    assert_eq!(truncate_with_ellipsis("Hello, world", 5), "Hello…");
+    assert_eq!(truncate_with_ellipsis("Hello", 5), "Hello");
+    assert_eq!(truncate_with_ellipsis("🦀🦀🦀🦀", 2), "🦀🦀…");
+    assert_eq!(truncate_with_ellipsis("héllo", 2), "hé…");
+    assert_eq!(truncate_with_ellipsis("abc", 0), "…");
+    assert_eq!(truncate_with_ellipsis("", 0), "");
+}
+
+#[test]
+fn test_pad_left() {
+    assert_eq!(pad_left("é", 3, '*'), "**é");
+    assert_eq!(pad_left("42", 5, '0'), "00042");
+    assert_eq!(pad_left("ab", 4, '🦀'), "🦀🦀ab");
+    assert_eq!(pad_left("wider", 3, ' '), "wider");
+    assert_eq!(pad_left("exact", 5, ' '), "exact");
+    assert_eq!(pad_left("", 2, '-'), "--");
+}
+
+#[test]
+fn test_pad_right() {
+    assert_eq!(pad_right("é", 3, '*'), "é**");
+    assert_eq!(pad_right("ab", 4, 'ß'), "abßß");
+    assert_eq!(pad_right("🦀🦀🦀", 2, ' '), "🦀🦀🦀");
+    assert_eq!(pad_right("", 0, ' '), "");
+}
+
+#[test]
+fn test_reverse_string() {
+    assert_eq!(reverse_string("🦀abc"), "cba🦀");
+    assert_eq!(reverse_string("héllo, 世界"), "界世 ,olléh");
+    assert_eq!(reverse_string("a"), "a");
+    assert_eq!(reverse_string(""), "");
+    assert_eq!(reverse_string(&reverse_string("round trip ✓")), "round trip ✓");
+}
+
+#[test]
+fn test_reverse_string_splits_combining_marks() {
+    // "e" followed by U+0301 COMBINING ACUTE ACCENT is reversed per scalar value.
+    assert_eq!(reverse_string("e\u{301}x"), "x\u{301}e");
+}
+
+#[test]
+fn test_collapse_whitespace() {
+    assert_eq!(collapse_whitespace("a\t\t b\n c "), "a b c");
+    assert_eq!(collapse_whitespace("  hello\r\n\u{3000}world  "), "hello world");
+    assert_eq!(collapse_whitespace("single"), "single");
+    assert_eq!(collapse_whitespace(" \t\n "), "");
+    assert_eq!(collapse_whitespace(""), "");
+}
+
+#[test]
+fn test_case_conversion_cross_product() {
+    let inputs = ["helloWorld", "HelloWorld", "hello_world", "hello-world", "Hello World", "HELLO_WORLD"];
+    for input in inputs {
+        assert_eq!(to_snake_case(input), "hello_world", "{:?}", input);
+        assert_eq!(to_kebab_case(input), "hello-world", "{:?}", input);
+        assert_eq!(to_camel_case(input), "helloWorld", "{:?}", input);
+        assert_eq!(to_pascal_case(input), "HelloWorld", "{:?}", input);
+    }
+}
+
+#[test]
+fn test_case_conversion_separators() {
+    assert_eq!(to_snake_case("__hello--world  "), "hello_world");
+    assert_eq!(to_kebab_case("  hello \t world"), "hello-world");
+    assert_eq!(to_camel_case("-_-"), "");
+    assert_eq!(to_pascal_case(""), "");
+}
+
+#[test]
+fn test_case_conversion_acronyms_and_digits() {
+    assert_eq!(to_snake_case("HTTPServer"), "http_server");
+    assert_eq!(to_snake_case("parseHTTP"), "parse_http");
+    assert_eq!(to_snake_case("version2Beta"), "version2_beta");
+    assert_eq!(to_camel_case("user_id_2"), "userId2");
+    assert_eq!(to_pascal_case("xml http request"), "XmlHttpRequest");
+}
+
+#[test]
+fn test_to_title_case() {
+    assert_eq!(to_title_case("hello WORLD"), "Hello World");
+    assert_eq!(to_title_case("the quick-brown fox"), "The Quick-brown Fox");
+    assert_eq!(to_title_case("ß straße"), "SS Straße");
+    assert_eq!(to_title_case(""), "");
+}
+
+#[test]
+fn test_to_title_case_preserves_whitespace() {
+    assert_eq!(to_title_case("  a  b\t\nc "), "  A  B\t\nC ");
+    assert_eq!(to_title_case("   "), "   ");
+}
+
+#[test]
+fn test_slugify() {
+    assert_eq!(slugify("Hello, World!  "), "hello-world");
+    assert_eq!(slugify("  --Rust 2024: What's New?--  "), "rust-2024-what-s-new");
+    assert_eq!(slugify("already-a-slug"), "already-a-slug");
+    assert_eq!(slugify("Crème brûlée"), "creme-brulee");
+    assert_eq!(slugify("I ❤ Rust"), "i-rust");
+    assert_eq!(slugify("日本"), "");
+    assert_eq!(slugify(""), "");
+}
+
+#[test]
+fn test_count_lines() {
+    assert_eq!(count_lines(""), 0);
+    assert_eq!(count_lines("a"), 1);
+    assert_eq!(count_lines("a\nb"), 2);
+    assert_eq!(count_lines("a\n"), 1);
+    assert_eq!(count_lines("\n"), 1);
+    assert_eq!(count_lines("a\n\nb"), 3);
+}
+
+#[test]
+fn test_count_lines_crlf() {
+    assert_eq!(count_lines("a\r\nb"), 2);
+    assert_eq!(count_lines("a\r\nb\r\n"), 2);
+    assert_eq!(count_lines("a\r\nb\nc"), 3);
+}
+
+#[test]
+fn test_count_words() {
+    assert_eq!(count_words("  a  b "), 2);
+    assert_eq!(count_words("one\ttwo\nthree"), 3);
+    assert_eq!(count_words("word"), 1);
+    assert_eq!(count_words(" \t\n "), 0);
+    assert_eq!(count_words(""), 0);
+}
+
+#[test]
+fn test_count_chars() {
+    assert_eq!(count_chars("🦀"), 1);
+    assert_eq!(count_chars("abc"), 3);
+    assert_eq!(count_chars("héllo, 世界"), 9);
+    assert_eq!(count_chars("e\u{301}"), 2);
+    assert_eq!(count_chars(""), 0);
+}
+
+#[test]
+fn test_wrap_text() {
+    assert_eq!(wrap_text("the quick brown fox", 10), "the quick\nbrown fox");
+    assert_eq!(wrap_text("short", 10), "short");
+    assert_eq!(wrap_text("", 10), "");
+}
+
+#[test]
+fn test_wrap_text_preserves_newlines() {
+    let text = "first paragraph here\n\nsecond one";
+    assert_eq!(wrap_text(text, 9), "first\nparagraph\nhere\n\nsecond\none");
+    assert_eq!(wrap_text("a\r\nb", 10), "a\nb");
+}
+
+#[test]
+fn test_wrap_text_long_words() {
+    assert_eq!(wrap_text("a verylongword b", 4), "a\nverylongword\nb");
+    assert_eq!(wrap_text("héllo wörld", 5), "héllo\nwörld");
+}
+
+#[test]
+fn test_edit_script_basic() {
+    assert_eq!(
+        edit_script("cat", "cut"),
+        vec![EditOp::Keep('c'), EditOp::Substitute('a', 'u'), EditOp::Keep('t')]
+    );
+    assert_eq!(edit_script("ab", "b"), vec![EditOp::Delete('a'), EditOp::Keep('b')]);
+    assert_eq!(edit_script("b", "ab"), vec![EditOp::Insert('a'), EditOp::Keep('b')]);
+    assert_eq!(edit_script("", ""), vec![]);
+    assert_eq!(edit_script("", "ab"), vec![EditOp::Insert('a'), EditOp::Insert('b')]);
+}
+
+#[test]
+fn test_edit_script_reconstructs_target() {
+    let pairs = [("kitten", "sitting"), ("sunday", "saturday"), ("🦀 rust", "rust 🦀"), ("abc", "")];
+    for (a, b) in pairs {
+        let ops = edit_script(a, b);
+        let source: String = ops
+            .iter()
+            .filter_map(|op| match op {
+                EditOp::Keep(c) | EditOp::Delete(c) | EditOp::Substitute(c, _) => Some(*c),
+                EditOp::Insert(_) => None,
+            })
+            .collect();
+        let target: String = ops
+            .iter()
+            .filter_map(|op| match op {
+                EditOp::Keep(c) | EditOp::Insert(c) | EditOp::Substitute(_, c) => Some(*c),
+                EditOp::Delete(_) => None,
+            })
+            .collect();
+        assert_eq!(source, a);
+        assert_eq!(target, b);
+    }
+
+    let edits = edit_script("kitten", "sitting")
+        .iter()
+        .filter(|op| !matches!(op, EditOp::Keep(_)))
+        .count();
+    assert_eq!(edits, 3);
+}
+
+#[test]
+fn test_longest_common_subsequence() {
+    assert_eq!(longest_common_subsequence("abcdef", "acf"), "acf");
+    assert_eq!(longest_common_subsequence("AGGTAB", "GXTXAYB"), "GTAB");
+    assert_eq!(longest_common_subsequence("abc", "xyz"), "");
+    assert_eq!(longest_common_subsequence("", "abc"), "");
+    assert_eq!(longest_common_subsequence("🦀a🦀", "a🦀"), "a🦀");
+}
+
+#[test]
+fn test_lcs_len() {
+    assert_eq!(lcs_len("ABCBDAB", "BDCABA"), 4);
+    assert_eq!(lcs_len("AGGTAB", "GXTXAYB"), 4);
+    assert_eq!(lcs_len("", ""), 0);
+    assert_eq!(lcs_len("same", "same"), 4);
+    assert_eq!(
+        lcs_len("ABCBDAB", "BDCABA"),
+        longest_common_subsequence("ABCBDAB", "BDCABA").chars().count()
+    );
+}
+
+#[test]
+fn test_convert_map_keys() {
+    let converted = convert_map_keys(&create_test_map(), |k| k.to_uppercase());
+    assert_eq!(converted.len(), 3);
+    assert_eq!(converted.get("NAME"), Some(&"John".to_string()));
+    assert_eq!(converted.get("CITY"), Some(&"New York".to_string()));
+    assert_eq!(converted.get("name"), None);
+}
+
+#[test]
+fn test_convert_map_keys_collision() {
+    let mut map = HashMap::new();
+    map.insert("a_b".to_string(), 1);
+    map.insert("a-b".to_string(), 2);
+    let converted = convert_map_keys(&map, |k| k.replace('-', "_"));
+    assert_eq!(converted.len(), 1);
+    assert!(matches!(converted.get("a_b"), Some(1) | Some(2)));
+}
+
+#[test]
+fn test_replace_multiple_placeholders_once() {
+    let template = "{{name}} is {{age}} years old and works as {{job}}.";
+    let result = replace_multiple_placeholders_once(template, &create_test_map());
+    assert_eq!(result, "John is 30 years old and works as {{job}}.");
+    assert_eq!(replace_multiple_placeholders_once("", &create_test_map()), "");
+    assert_eq!(replace_multiple_placeholders_once("{{name", &create_test_map()), "{{name");
 }
 
 #[test]
-fn test_special_characters_in_replacement() {
-    let input = "Hello {{name}}!";
-    let result = replace_placeholder(input, "name", "$&*");
-    assert_eq!(result, "Hello $&*!");
+fn test_replace_multiple_placeholders_once_no_reexpansion() {
+    let mut replacements = HashMap::new();
+    replacements.insert("outer".to_string(), "{{inner}}".to_string());
+    replacements.insert("inner".to_string(), "value".to_string());
+    for _ in 0..10 {
+        let result = replace_multiple_placeholders_once("Nested: {{outer}}", &replacements);
+        assert_eq!(result, "Nested: {{inner}}");
+    }
 }
 
 #[test]
-fn test_empty_replacement() {
-    let input = "Hello {{name}}!";
-    let result = replace_placeholder(input, "name", "");
-    assert_eq!(result, "Hello !");
+fn test_replace_multiple_placeholders_once_unknown_prefix() {
+    let mut replacements = HashMap::new();
+    replacements.insert("b".to_string(), "B".to_string());
+    assert_eq!(replace_multiple_placeholders_once("{{a{{b}}", &replacements), "{{aB");
+    assert_eq!(replace_multiple_placeholders_once("{{{b}}", &replacements), "{B");
 }
 
 #[test]
-fn test_placeholder_with_spaces() {
-    let input = "Hello {{user name}}!";
-    let result = replace_placeholder(input, "user name", "John Doe");
-    assert_eq!(result, "Hello John Doe!");
+fn test_replace_multiple_placeholders_checked() {
+    let template = "{{name}} is {{age}} and works as {{job}} in {{place}}, {{job}}.";
+    let (result, missing) = replace_multiple_placeholders_checked(template, &create_test_map());
+    assert_eq!(result, "John is 30 and works as {{job}} in {{place}}, {{job}}.");
+    assert_eq!(missing, vec!["job", "place"]);
 }
 
 #[test]
-fn test_nested_placeholders() {
-    let input = "Hello {{outer{{inner}}}}!";
-    let result = replace_placeholder(input, "outer{{inner}}", "World");
-    assert_eq!(result, "Hello World!");
+fn test_replace_multiple_placeholders_checked_all_resolved() {
+    let template = "{{name}} lives in {{city}}";
+    let (result, missing) = replace_multiple_placeholders_checked(template, &create_test_map());
+    assert_eq!(result, "John lives in New York");
+    assert!(missing.is_empty());
 }
 
-fn create_test_map() -> HashMap<String, String> {
-    let mut map = HashMap::new();
-    map.insert("name".to_string(), "John".to_string());
-    map.insert("age".to_string(), "30".to_string());
-    map.insert("city".to_string(), "New York".to_string());
-    map
+#[test]
+fn test_replace_multiple_placeholders_checked_stray_braces() {
+    let (result, missing) = replace_multiple_placeholders_checked("{{a{{name}}", &create_test_map());
+    assert_eq!(result, "{{aJohn");
+    assert!(missing.is_empty());
 }
 
 #[test]
-fn test_multiple_replacements() {
-    let template = "{{name}} is {{age}} years old and lives in {{city}}.";
-    let replacements = create_test_map();
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "John is 30 years old and lives in New York.");
+fn test_compare_versions() {
+    assert_eq!(compare_versions("1.2", "1.10"), Ordering::Less);
+    assert_eq!(compare_versions("1.10", "1.2"), Ordering::Greater);
+    assert_eq!(compare_versions("1.2.3", "1.2.3"), Ordering::Equal);
+    assert_eq!(compare_versions("0.9", "1.0"), Ordering::Less);
 }
 
 #[test]
-fn test_repeated_placeholders() {
-    let template = "{{name}} {{name}} {{name}}";
-    let replacements = create_test_map();
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "John John John");
+fn test_compare_versions_missing_components() {
+    assert_eq!(compare_versions("1.2", "1.2.0"), Ordering::Equal);
+    assert_eq!(compare_versions("1.2", "1.2.1"), Ordering::Less);
+    assert_eq!(compare_versions("1", "1.0.0.0"), Ordering::Equal);
+    assert_eq!(compare_versions("1.01", "1.1"), Ordering::Equal);
 }
 
 #[test]
-fn test_missing_placeholder() {
-    let template = "{{name}} is {{age}} years old and works as {{job}}.";
-    let replacements = create_test_map();
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "John is 30 years old and works as {{job}}.");
+fn test_find_placeholders() {
+    assert_eq!(find_placeholders("{{a}} {{b}} {{a}}"), vec!["a", "b"]);
+    assert_eq!(find_placeholders("Hello {{user name}}!"), vec!["user name"]);
+    assert_eq!(find_placeholders("no placeholders"), Vec::<String>::new());
+    assert_eq!(find_placeholders("{{unclosed"), Vec::<String>::new());
+    assert_eq!(find_placeholders("{{{x}}}"), vec!["x"]);
 }
 
 #[test]
-fn test_empty_template() {
-    let template = "";
-    let replacements = create_test_map();
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "");
+fn test_detect_line_ending() {
+    assert_eq!(detect_line_ending("a\nb\nc"), LineEnding::Lf);
+    assert_eq!(detect_line_ending("a\r\nb\r\n"), LineEnding::CrLf);
+    assert_eq!(detect_line_ending("a\rb\r"), LineEnding::Cr);
+    assert_eq!(detect_line_ending(""), LineEnding::None);
+    assert_eq!(detect_line_ending("single line"), LineEnding::None);
 }
 
 #[test]
-fn test_empty_map() {
-    let template = "Hello {{name}}!";
-    let replacements = HashMap::new();
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "Hello {{name}}!");
+fn test_extract_numbers() {
+    assert_eq!(extract_numbers("temp 21.5C, 30F"), vec![21.5, 30.0]);
+    assert_eq!(extract_numbers("delta -4.25 then +7"), vec![-4.25, 7.0]);
+    assert_eq!(extract_numbers("v1.2.3"), vec![1.2, 3.0]);
+    assert_eq!(extract_numbers("no digits here"), Vec::<f64>::new());
 }
 
 #[test]
-fn test_special_characters() {
-    let mut replacements = HashMap::new();
-    replacements.insert("special".to_string(), "$&*^%".to_string());
-    let template = "Special chars: {{special}}";
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "Special chars: $&*^%");
+fn test_extract_integers() {
+    assert_eq!(extract_integers("took 120ms, 21.5% cpu, -3 retries"), vec![120, -3]);
+    assert_eq!(extract_integers("id=42 code=007"), vec![42, 7]);
+    assert_eq!(extract_integers("huge 99999999999999999999 small 1"), vec![1]);
+    assert_eq!(extract_integers("call 555-1234"), vec![555, 1234]);
+    assert_eq!(extract_integers("v2-3 (-4) x+5"), vec![2, 3, -4, 5]);
+    assert_eq!(extract_integers(""), Vec::<i64>::new());
 }
 
 #[test]
-fn test_nested_replacement() {
-    let mut replacements = HashMap::new();
-    replacements.insert("outer".to_string(), "{{inner}}".to_string());
-    replacements.insert("inner".to_string(), "value".to_string());
-    let template = "Nested: {{outer}}";
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "Nested: value");
+fn test_mask_digits() {
+    assert_eq!(mask_digits("call 555-1234", '*'), "call ***-****");
+    assert_eq!(mask_digits("4111 1111 1111 1111", '#'), "#### #### #### ####");
+    assert_eq!(mask_digits("no digits", '*'), "no digits");
+    assert_eq!(mask_digits("pin ٣٤", '*'), "pin ٣٤");
+    assert_eq!(mask_digits("", '*'), "");
 }
 
 #[test]
-fn test_unicode_characters() {
-    let mut replacements = HashMap::new();
-    replacements.insert("greeting".to_string(), "你好".to_string());
-    replacements.insert("name".to_string(), "José".to_string());
-    let template = "{{greeting}}, {{name}}!";
-    let result = replace_multiple_placeholders(template, &replacements);
-    assert_eq!(result, "你好, José!");
+fn test_detect_line_ending_mixed() {
+    assert_eq!(detect_line_ending("a\nb\r\n"), LineEnding::Mixed);
+    assert_eq!(detect_line_ending("a\rb\n"), LineEnding::Mixed);
+    assert_eq!(detect_line_ending("a\r\nb\r"), LineEnding::Mixed);
 }
 
 #[test]
@@ -383,6 +2010,60 @@ fn test_dedup_all_same() {
     assert_eq!(same, vec![1]);
 }
 
+#[test]
+fn test_dedup_owned_strings() {
+    let mut words = vec![
+        "apple".to_string(),
+        "banana".to_string(),
+        "apple".to_string(),
+        "cherry".to_string(),
+        "banana".to_string(),
+    ];
+    dedup(&mut words);
+    assert_eq!(words, vec!["apple", "banana", "cherry"]);
+}
+
+#[test]
+fn test_dedup_non_copy_struct() {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Item(String);
+
+    let mut items = vec![Item("x".into()), Item("y".into()), Item("x".into())];
+    dedup(&mut items);
+    assert_eq!(items, vec![Item("x".into()), Item("y".into())]);
+}
+
+#[test]
+fn test_dedup_by_key() {
+    #[derive(Debug, PartialEq)]
+    struct User {
+        id: u32,
+        name: &'static str,
+    }
+
+    let mut users = vec![
+        User { id: 1, name: "alice" },
+        User { id: 2, name: "bob" },
+        User { id: 1, name: "alice again" },
+        User { id: 3, name: "carol" },
+        User { id: 2, name: "bob again" },
+    ];
+    dedup_by_key(&mut users, |user| user.id);
+    let names: Vec<_> = users.iter().map(|user| user.name).collect();
+    assert_eq!(names, vec!["alice", "bob", "carol"]);
+}
+
+#[test]
+fn test_dedup_by_key_derived() {
+    let mut words = vec!["Apple", "apple", "Banana", "APPLE", "banana"];
+    dedup_by_key(&mut words, |word| word.to_lowercase());
+    assert_eq!(words, vec!["Apple", "Banana"]);
+
+    let mut empty: Vec<i32> = vec![];
+    dedup_by_key(&mut empty, |&x| x);
+    assert!(empty.is_empty());
+}
+
 #[test]
 fn test_retain_even_numbers() {
     let mut numbers = vec![1, 2, 3, 4, 5, 6];
@@ -467,6 +2148,45 @@ fn test_retain_custom_struct() {
     assert_eq!(people[1].name, "Charlie");
 }
 
+#[test]
+fn test_partition_vec() {
+    let (even, odd) = partition_vec(&[1, 2, 3, 4, 5, 6], |&x| x % 2 == 0);
+    assert_eq!(even, vec![2, 4, 6]);
+    assert_eq!(odd, vec![1, 3, 5]);
+}
+
+#[test]
+fn test_partition_vec_all_true() {
+    let (matched, unmatched) = partition_vec(&[1, 2, 3], |_| true);
+    assert_eq!(matched, vec![1, 2, 3]);
+    assert_eq!(unmatched, Vec::<i32>::new());
+}
+
+#[test]
+fn test_partition_vec_all_false() {
+    let (matched, unmatched) = partition_vec(&[1, 2, 3], |_| false);
+    assert_eq!(matched, Vec::<i32>::new());
+    assert_eq!(unmatched, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_partition_vec_custom_struct() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row {
+        id: u32,
+        valid: bool,
+    }
+
+    let rows = vec![
+        Row { id: 1, valid: true },
+        Row { id: 2, valid: false },
+        Row { id: 3, valid: true },
+    ];
+    let (valid, invalid) = partition_vec(&rows, |row| row.valid);
+    assert_eq!(valid.iter().map(|r| r.id).collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(invalid, vec![Row { id: 2, valid: false }]);
+}
+
 #[test]
 fn test_reverse_odd_length_vector() {
     let mut vec = vec![1, 2, 3, 4, 5];
@@ -533,6 +2253,40 @@ fn test_reverse_preserves_elements() {
     assert_eq!(original_sum, reversed_sum);
 }
 
+#[test]
+fn test_rotate_left_in_place() {
+    let mut vec = vec![1, 2, 3, 4, 5];
+    rotate_left_in_place(&mut vec, 0);
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+    rotate_left_in_place(&mut vec, 2);
+    assert_eq!(vec, vec![3, 4, 5, 1, 2]);
+    rotate_left_in_place(&mut vec, 5);
+    assert_eq!(vec, vec![3, 4, 5, 1, 2]);
+    rotate_left_in_place(&mut vec, 7);
+    assert_eq!(vec, vec![5, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_rotate_right_in_place() {
+    let mut vec = vec![1, 2, 3, 4, 5];
+    rotate_right_in_place(&mut vec, 0);
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+    rotate_right_in_place(&mut vec, 2);
+    assert_eq!(vec, vec![4, 5, 1, 2, 3]);
+    rotate_right_in_place(&mut vec, 5);
+    assert_eq!(vec, vec![4, 5, 1, 2, 3]);
+    rotate_right_in_place(&mut vec, 8);
+    assert_eq!(vec, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_rotate_empty_vector() {
+    let mut vec: Vec<i32> = vec![];
+    rotate_left_in_place(&mut vec, 3);
+    rotate_right_in_place(&mut vec, 3);
+    assert!(vec.is_empty());
+}
+
 #[test]
 fn test_split_at_middle() {
     let mut vec = vec![1, 2, 3, 4, 5];
@@ -631,6 +2385,23 @@ fn test_split_with_non_copy_type() {
     assert_eq!(vec, original); // Original vector unchanged
 }
 
+#[test]
+fn test_split_at_first() {
+    let (left, right) = split_at_first(&[1, 2, 3, 4, 5], |&x| x > 2).unwrap();
+    assert_eq!(left, vec![1, 2]);
+    assert_eq!(right, vec![3, 4, 5]);
+
+    let (left, right) = split_at_first(&[1, 2, 3], |&x| x == 1).unwrap();
+    assert_eq!(left, Vec::<i32>::new());
+    assert_eq!(right, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_split_at_first_no_match() {
+    assert_eq!(split_at_first(&[1, 2, 3], |&x| x > 10), None);
+    assert_eq!(split_at_first::<i32>(&[], |_| true), None);
+}
+
 #[test]
 fn test_empty_vec() {
     let input: Vec<i32> = vec![];
@@ -682,3 +2453,302 @@ fn test_large_input() {
     let expected: Vec<i32> = (0..1000).collect();
     assert_eq!(get_unique(&input), expected);
 }
+
+#[test]
+fn test_similarity_ratio_identical() {
+    assert_eq!(similarity_ratio("hello", "hello"), 1.0);
+    assert_eq!(similarity_ratio("", ""), 1.0);
+}
+
+#[test]
+fn test_similarity_ratio_partial() {
+    assert_eq!(similarity_ratio("abcd", "abce"), 0.75);
+    assert!((similarity_ratio("kitten", "sitting") - (1.0 - 3.0 / 7.0)).abs() < 1e-9);
+    assert_eq!(similarity_ratio("abc", ""), 0.0);
+}
+
+#[test]
+fn test_similarity_ratio_unicode() {
+    assert_eq!(similarity_ratio("café", "cafe"), 0.75);
+    assert_eq!(similarity_ratio("abc", "xyz"), 0.0);
+}
+
+#[test]
+fn test_remove_diacritics() {
+    assert_eq!(remove_diacritics("café"), "cafe");
+    assert_eq!(remove_diacritics("Crème Brûlée"), "Creme Brulee");
+    assert_eq!(remove_diacritics("Łódź"), "Lodz");
+    assert_eq!(remove_diacritics("plain ascii"), "plain ascii");
+    assert_eq!(remove_diacritics(""), "");
+}
+
+#[test]
+fn test_remove_diacritics_combining_marks() {
+    assert_eq!(remove_diacritics("cafe\u{301}"), "cafe");
+    assert_eq!(remove_diacritics("n\u{303}"), "n");
+    assert_eq!(remove_diacritics("日本"), "日本");
+}
+
+#[test]
+fn test_is_ascii_str() {
+    assert!(is_ascii_str("Hello, world!"));
+    assert!(is_ascii_str(""));
+    assert!(!is_ascii_str("café"));
+    assert!(!is_ascii_str("🦀"));
+}
+
+#[test]
+fn test_run_lengths() {
+    assert_eq!(run_lengths(&[1, 1, 2, 3, 3, 3]), vec![(1, 2), (2, 1), (3, 3)]);
+    assert_eq!(run_lengths(&[1, 2, 1]), vec![(1, 1), (2, 1), (1, 1)]);
+    assert_eq!(run_lengths::<i32>(&[]), vec![]);
+}
+
+#[test]
+fn test_run_lengths_strings() {
+    let input = vec!["a", "a", "b", "b", "b"];
+    assert_eq!(run_lengths(&input), vec![("a", 2), ("b", 3)]);
+}
+
+#[test]
+fn test_from_run_lengths() {
+    assert_eq!(from_run_lengths(&[(1, 2), (2, 1), (3, 3)]), vec![1, 1, 2, 3, 3, 3]);
+    assert_eq!(from_run_lengths(&[("a", 0), ("b", 2)]), vec!["b", "b"]);
+    assert_eq!(from_run_lengths::<i32>(&[]), vec![]);
+}
+
+#[test]
+fn test_run_lengths_roundtrip() {
+    let input = vec![5, 5, 5, 1, 2, 2, 5, 5];
+    assert_eq!(from_run_lengths(&run_lengths(&input)), input);
+}
+
+#[test]
+fn test_contains_subsequence() {
+    let events = vec!["start", "load", "render", "idle", "stop"];
+    assert!(contains_subsequence(&events, &["start", "render", "stop"]));
+    assert!(contains_subsequence(&events, &["load"]));
+    assert!(!contains_subsequence(&events, &["render", "load"]));
+    assert!(!contains_subsequence(&events, &["start", "crash"]));
+}
+
+#[test]
+fn test_contains_subsequence_edge_cases() {
+    assert!(contains_subsequence::<i32>(&[], &[]));
+    assert!(contains_subsequence(&[1, 2], &[]));
+    assert!(!contains_subsequence(&[], &[1]));
+    assert!(contains_subsequence(&[1, 1, 2], &[1, 1]));
+    assert!(!contains_subsequence(&[1, 2], &[1, 1]));
+}
+
+#[test]
+fn test_chunk_vec() {
+    assert_eq!(chunk_vec(&[1, 2, 3, 4, 5], 2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+    assert_eq!(chunk_vec(&[1, 2, 3, 4], 2), vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(chunk_vec(&[1, 2], 5), vec![vec![1, 2]]);
+    assert_eq!(chunk_vec::<i32>(&[], 3), Vec::<Vec<i32>>::new());
+}
+
+#[test]
+fn test_chunk_vec_non_copy() {
+    let input = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+    assert_eq!(chunk_vec(&input, 2), vec![vec!["a", "b"], vec!["c"]]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be greater than 0")]
+fn test_chunk_vec_zero_size() {
+    chunk_vec(&[1, 2, 3], 0);
+}
+
+#[test]
+fn test_window_vec() {
+    assert_eq!(window_vec(&[1, 2, 3, 4], 2), vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    assert_eq!(window_vec(&[1, 2, 3], 3), vec![vec![1, 2, 3]]);
+    assert_eq!(window_vec(&[1, 2, 3], 1), vec![vec![1], vec![2], vec![3]]);
+    assert_eq!(window_vec(&[1, 2], 5), Vec::<Vec<i32>>::new());
+    assert_eq!(window_vec::<i32>(&[], 1), Vec::<Vec<i32>>::new());
+}
+
+#[test]
+#[should_panic(expected = "window size must be greater than 0")]
+fn test_window_vec_zero_size() {
+    window_vec(&[1, 2, 3], 0);
+}
+
+#[test]
+fn test_moving_average() {
+    assert_eq!(moving_average(&[1.0, 2.0, 3.0, 4.0, 5.0], 3), vec![2.0, 3.0, 4.0]);
+    assert_eq!(moving_average(&[2.0, 4.0], 1), vec![2.0, 4.0]);
+    assert_eq!(moving_average(&[1.0, 2.0, 6.0], 3), vec![3.0]);
+    assert_eq!(moving_average(&[1.0, 2.0], 3), Vec::<f64>::new());
+    assert_eq!(moving_average(&[], 1), Vec::<f64>::new());
+}
+
+#[test]
+#[should_panic(expected = "window size must be greater than 0")]
+fn test_moving_average_zero_window() {
+    moving_average(&[1.0, 2.0], 0);
+}
+
+#[test]
+fn test_split_vec_into_n() {
+    let input: Vec<i32> = (0..10).collect();
+    let parts = split_vec_into_n(&input, 3);
+    let sizes: Vec<usize> = parts.iter().map(Vec::len).collect();
+    assert_eq!(sizes, vec![4, 3, 3]);
+    assert_eq!(flatten_vec(&parts), input);
+
+    assert_eq!(split_vec_into_n(&[1, 2, 3, 4], 2), vec![vec![1, 2], vec![3, 4]]);
+    assert_eq!(split_vec_into_n(&[1, 2, 3], 1), vec![vec![1, 2, 3]]);
+}
+
+#[test]
+fn test_split_vec_into_n_more_chunks_than_elements() {
+    assert_eq!(split_vec_into_n(&[1, 2], 4), vec![vec![1], vec![2], vec![], vec![]]);
+    assert_eq!(split_vec_into_n::<i32>(&[], 2), vec![Vec::<i32>::new(), vec![]]);
+}
+
+#[test]
+#[should_panic(expected = "number of chunks must be greater than 0")]
+fn test_split_vec_into_n_zero() {
+    split_vec_into_n(&[1, 2, 3], 0);
+}
+
+#[test]
+fn test_flatten_vec() {
+    assert_eq!(flatten_vec(&[vec![1, 2], vec![3], vec![4, 5]]), vec![1, 2, 3, 4, 5]);
+    assert_eq!(flatten_vec(&[vec![], vec![1], vec![]]), vec![1]);
+    assert_eq!(flatten_vec::<i32>(&[]), Vec::<i32>::new());
+}
+
+#[test]
+fn test_flatten_vec_non_copy() {
+    let nested = vec![vec!["a".to_string()], vec!["b".to_string(), "c".to_string()]];
+    let flat = flatten_vec(&nested);
+    assert_eq!(flat, vec!["a", "b", "c"]);
+    assert_eq!(nested.len(), 2);
+}
+
+#[test]
+fn test_zip_vecs() {
+    assert_eq!(zip_vecs(&[1, 2, 3], &['a', 'b', 'c']), vec![(1, 'a'), (2, 'b'), (3, 'c')]);
+    assert_eq!(zip_vecs(&[1, 2, 3], &['a']), vec![(1, 'a')]);
+    assert_eq!(zip_vecs(&[1], &['a', 'b', 'c']), vec![(1, 'a')]);
+    assert_eq!(zip_vecs::<i32, char>(&[], &['a']), Vec::<(i32, char)>::new());
+}
+
+#[test]
+fn test_unzip_vec() {
+    let pairs = vec![("a".to_string(), 1), ("b".to_string(), 2)];
+    let (names, values) = unzip_vec(&pairs);
+    assert_eq!(names, vec!["a", "b"]);
+    assert_eq!(values, vec![1, 2]);
+    assert_eq!(unzip_vec::<i32, i32>(&[]), (vec![], vec![]));
+}
+
+#[test]
+fn test_interleave() {
+    assert_eq!(interleave(&[1, 3], &[2, 4]), vec![1, 2, 3, 4]);
+    assert_eq!(interleave(&[1, 3, 5], &[2, 4]), vec![1, 2, 3, 4, 5]);
+    assert_eq!(interleave(&[1], &[2, 4, 6]), vec![1, 2, 4, 6]);
+    assert_eq!(interleave(&[], &[2, 4]), vec![2, 4]);
+    assert_eq!(interleave::<i32>(&[], &[]), Vec::<i32>::new());
+}
+
+#[test]
+fn test_interleave_non_copy() {
+    let a = vec!["a".to_string(), "c".to_string()];
+    let b = vec!["b".to_string()];
+    assert_eq!(interleave(&a, &b), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_zip_unzip_roundtrip() {
+    let (a, b) = (vec![1, 2, 3], vec!["x", "y", "z"]);
+    assert_eq!(unzip_vec(&zip_vecs(&a, &b)), (a, b));
+}
+
+#[test]
+fn test_group_by_key() {
+    let groups = group_by_key(&[1, 2, 3, 4], |x| x % 2);
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[&0], vec![2, 4]);
+    assert_eq!(groups[&1], vec![1, 3]);
+}
+
+#[test]
+fn test_group_by_key_strings() {
+    let words = vec!["apple", "avocado", "banana", "blueberry", "cherry"];
+    let groups = group_by_key(&words, |w| w.chars().next().unwrap());
+    assert_eq!(groups[&'a'], vec!["apple", "avocado"]);
+    assert_eq!(groups[&'b'], vec!["banana", "blueberry"]);
+    assert_eq!(groups[&'c'], vec!["cherry"]);
+    assert!(group_by_key::<i32, i32>(&[], |x| *x).is_empty());
+}
+
+#[test]
+fn test_intersection() {
+    assert_eq!(intersection(&[3, 1, 2, 3, 4], &[4, 3, 5]), vec![3, 4]);
+    assert_eq!(intersection(&["a", "b", "c"], &["c", "a"]), vec!["a", "c"]);
+    assert_eq!(intersection(&[1, 2], &[3, 4]), Vec::<i32>::new());
+}
+
+#[test]
+fn test_intersection_empty() {
+    assert_eq!(intersection(&[], &[1, 2]), Vec::<i32>::new());
+    assert_eq!(intersection(&[1, 2], &[]), Vec::<i32>::new());
+}
+
+#[test]
+fn test_difference() {
+    assert_eq!(difference(&[3, 1, 2, 3, 4], &[4, 5]), vec![3, 1, 2]);
+    assert_eq!(difference(&[1, 2], &[1, 2, 3]), Vec::<i32>::new());
+}
+
+#[test]
+fn test_difference_empty() {
+    assert_eq!(difference(&[2, 1, 2, 3], &[]), get_unique(&[2, 1, 2, 3]));
+    assert_eq!(difference::<i32>(&[], &[1]), Vec::<i32>::new());
+}
+
+#[test]
+fn test_find_duplicates() {
+    assert_eq!(find_duplicates(&[1, 2, 2, 3, 3, 3, 4]), vec![2, 3]);
+    assert_eq!(find_duplicates(&[3, 1, 3, 1, 2]), vec![3, 1]);
+    assert_eq!(find_duplicates(&[1, 2, 3]), Vec::<i32>::new());
+    assert_eq!(find_duplicates::<i32>(&[]), Vec::<i32>::new());
+}
+
+#[test]
+fn test_count_occurrences() {
+    let counts = count_occurrences(&[1, 2, 2, 3, 3, 3]);
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&1], 1);
+    assert_eq!(counts[&2], 2);
+    assert_eq!(counts[&3], 3);
+    assert!(count_occurrences::<i32>(&[]).is_empty());
+}
+
+#[test]
+fn test_count_occurrences_strings() {
+    let words = vec!["a".to_string(), "b".to_string(), "a".to_string()];
+    let counts = count_occurrences(&words);
+    assert_eq!(counts["a"], 2);
+    assert_eq!(counts["b"], 1);
+    assert!(counts.values().any(|&count| count > 1));
+}
+
+#[test]
+fn test_most_common() {
+    assert_eq!(most_common(&[1, 1, 2, 3, 3, 3], 2), vec![(3, 3), (1, 2)]);
+    assert_eq!(most_common(&[1, 1, 2, 3, 3, 3], 10), vec![(3, 3), (1, 2), (2, 1)]);
+    assert_eq!(most_common(&[1, 2, 3], 0), Vec::<(i32, usize)>::new());
+    assert_eq!(most_common::<i32>(&[], 3), Vec::<(i32, usize)>::new());
+}
+
+#[test]
+fn test_most_common_ties_keep_first_occurrence() {
+    assert_eq!(most_common(&["b", "a", "c", "a", "b", "c"], 3), vec![("b", 2), ("a", 2), ("c", 2)]);
+    assert_eq!(most_common(&[5, 4, 4, 5, 6], 2), vec![(5, 2), (4, 2)]);
+}
@@ -1,5 +1,8 @@
-use std::collections::HashSet;
-use std::hash::Hash;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// Removes duplicate elements from a vector in-place.
 ///
@@ -9,7 +12,7 @@ use std::hash::Hash;
 ///
 /// # Type Parameters
 ///
-/// * `T`: The type of elements in the vector. It must implement `Eq`, `Hash`, and `Copy` traits.
+/// * `T`: The type of elements in the vector. It must implement `Eq`, `Hash`, and `Clone` traits.
 ///
 /// # Arguments
 ///
@@ -21,16 +24,57 @@ use std::hash::Hash;
 /// let mut numbers = vec![1, 2, 3, 2, 4, 1, 5];
 /// byteutils::vec::dedup(&mut numbers);
 /// assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+///
+/// let mut words = vec!["a".to_string(), "b".to_string(), "a".to_string()];
+/// byteutils::vec::dedup(&mut words);
+/// assert_eq!(words, vec!["a", "b"]);
 /// ```
 ///
 /// # Note
 ///
-/// This function requires the `Copy` trait because it needs to copy elements
-/// into the HashSet. For types that don't implement `Copy`, consider using
-/// references or implementing a different deduplication strategy.
-pub fn dedup<T: Eq + Hash + Copy>(v: &mut Vec<T>) {
+/// Only the first occurrence of each unique element is cloned into the HashSet;
+/// duplicates are detected by lookup and removed without cloning.
+#[cfg(feature = "std")]
+pub fn dedup<T: Eq + Hash + Clone>(v: &mut Vec<T>) {
     let mut uniques = HashSet::new();
-    v.retain(|e| uniques.insert(*e));
+    v.retain(|e| {
+        if uniques.contains(e) {
+            false
+        } else {
+            uniques.insert(e.clone());
+            true
+        }
+    });
+}
+
+/// Removes elements whose derived key has already been seen, in-place.
+///
+/// Unlike `dedup`, elements are compared by the key returned from `key_fn`, so
+/// the element type itself does not need to implement `Hash` or `Eq`. The first
+/// element for each distinct key is kept and the order of the survivors is
+/// preserved. Unlike `Vec::dedup_by_key`, duplicates do not need to be adjacent.
+///
+/// # Type Parameters
+///
+/// * `T`: The type of elements in the vector.
+/// * `K`: The key type returned by `key_fn`. It must implement `Eq` and `Hash`.
+///
+/// # Arguments
+///
+/// * `v` - A mutable reference to the vector to be deduplicated.
+/// * `key_fn` - A closure that derives the comparison key for an element.
+///
+/// # Example
+///
+/// ```rust
+/// let mut users = vec![(1, "alice"), (2, "bob"), (1, "alice (copy)")];
+/// byteutils::vec::dedup_by_key(&mut users, |&(id, _)| id);
+/// assert_eq!(users, vec![(1, "alice"), (2, "bob")]);
+/// ```
+#[cfg(feature = "std")]
+pub fn dedup_by_key<T, K: Eq + Hash>(v: &mut Vec<T>, key_fn: impl Fn(&T) -> K) {
+    let mut seen = HashSet::new();
+    v.retain(|e| seen.insert(key_fn(e)));
 }
 
 /// Retains only the elements specified by the predicate.
@@ -55,6 +99,33 @@ pub fn retain_if<T>(v: &mut Vec<T>, predicate: impl Fn(&T) -> bool) {
     v.retain(predicate);
 }
 
+/// Splits a slice into the elements that match a predicate and those that don't.
+///
+/// Unlike `retain_if`, both groups are kept. Elements are cloned into two new
+/// vectors, and the relative order of elements within each group is preserved.
+///
+/// # Arguments
+///
+/// * `input` - The slice to partition
+/// * `predicate` - A closure that takes a reference to an element and returns a boolean
+///
+/// # Returns
+///
+/// A tuple `(matched, unmatched)` of the elements for which the predicate returned
+/// `true` and `false` respectively.
+///
+/// # Examples
+///
+/// ```
+/// let numbers = vec![1, 2, 3, 4, 5, 6];
+/// let (even, odd) = byteutils::vec::partition_vec(&numbers, |&x| x % 2 == 0);
+/// assert_eq!(even, vec![2, 4, 6]);
+/// assert_eq!(odd, vec![1, 3, 5]);
+/// ```
+pub fn partition_vec<T: Clone>(input: &[T], predicate: impl Fn(&T) -> bool) -> (Vec<T>, Vec<T>) {
+    input.iter().cloned().partition(|item| predicate(item))
+}
+
 /// Reverses the order of elements in the vector in place.
 ///
 /// This function modifies the original vector, reversing the order of its elements
@@ -76,6 +147,7 @@ pub fn retain_if<T>(v: &mut Vec<T>, predicate: impl Fn(&T) -> bool) {
 ///
 /// This function has a time complexity of O(n/2) where n is the length of the vector.
 /// It performs in-place swapping, which is memory-efficient for large vectors.
+#[allow(clippy::ptr_arg)]
 pub fn reverse_in_place<T>(v: &mut Vec<T>) {
     let len = v.len();
     for i in 0..len / 2 {
@@ -83,6 +155,58 @@ pub fn reverse_in_place<T>(v: &mut Vec<T>) {
     }
 }
 
+/// Rotates the elements of the vector to the left in place.
+///
+/// After the call, the element previously at index `n` is at index 0, and the
+/// first `n` elements wrap around to the end. Rotating by `n >= len` behaves like
+/// rotating by `n % len`, and rotating an empty vector does nothing.
+///
+/// # Arguments
+///
+/// * `v` - A mutable reference to the vector to be rotated
+/// * `n` - The number of positions to rotate by
+///
+/// # Examples
+///
+/// ```
+/// let mut vec = vec![1, 2, 3, 4, 5];
+/// byteutils::vec::rotate_left_in_place(&mut vec, 2);
+/// assert_eq!(vec, vec![3, 4, 5, 1, 2]);
+/// ```
+#[allow(clippy::ptr_arg)]
+pub fn rotate_left_in_place<T>(v: &mut Vec<T>, n: usize) {
+    if !v.is_empty() {
+        let len = v.len();
+        v.rotate_left(n % len);
+    }
+}
+
+/// Rotates the elements of the vector to the right in place.
+///
+/// After the call, the last `n` elements are moved to the front. Rotating by
+/// `n >= len` behaves like rotating by `n % len`, and rotating an empty vector
+/// does nothing.
+///
+/// # Arguments
+///
+/// * `v` - A mutable reference to the vector to be rotated
+/// * `n` - The number of positions to rotate by
+///
+/// # Examples
+///
+/// ```
+/// let mut vec = vec![1, 2, 3, 4, 5];
+/// byteutils::vec::rotate_right_in_place(&mut vec, 2);
+/// assert_eq!(vec, vec![4, 5, 1, 2, 3]);
+/// ```
+#[allow(clippy::ptr_arg)]
+pub fn rotate_right_in_place<T>(v: &mut Vec<T>, n: usize) {
+    if !v.is_empty() {
+        let len = v.len();
+        v.rotate_right(n % len);
+    }
+}
+
 /// Splits a vector into two at the given index, creating two new vectors.
 ///
 /// This function takes a mutable reference to a vector and an index, and returns
@@ -122,6 +246,7 @@ pub fn reverse_in_place<T>(v: &mut Vec<T>) {
 /// - If you only need to work with the split parts without creating new vectors or cloning elements,
 ///   consider using the standard library's `split_at` or `split_at_mut` methods instead.
 /// - The original vector `v` is not modified by this operation.
+#[allow(clippy::ptr_arg)]
 pub fn split_at_vec<T: Clone>(v: &mut Vec<T>, at: usize) -> (Vec<T>, Vec<T>) {
     if at > v.len() {
         panic!("index out of bounds");
@@ -130,6 +255,39 @@ pub fn split_at_vec<T: Clone>(v: &mut Vec<T>, at: usize) -> (Vec<T>, Vec<T>) {
     (left.to_vec(), right.to_vec())
 }
 
+/// Splits a slice into two new vectors at the first element matching a predicate.
+///
+/// The first vector contains the elements before the first match, and the second
+/// contains the matching element and everything after it. This is a
+/// predicate-driven complement to `split_at_vec`.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement the `Clone` trait
+///
+/// # Arguments
+///
+/// * `v` - The slice to split
+/// * `pred` - A closure that takes a reference to an element and returns a boolean
+///
+/// # Returns
+///
+/// `Some((before, from_match))`, or `None` if no element matches.
+///
+/// # Examples
+///
+/// ```
+/// let lines = vec!["title", "author", "", "body"];
+/// let (header, body) = byteutils::vec::split_at_first(&lines, |line| line.is_empty()).unwrap();
+/// assert_eq!(header, vec!["title", "author"]);
+/// assert_eq!(body, vec!["", "body"]);
+/// ```
+pub fn split_at_first<T: Clone>(v: &[T], pred: impl Fn(&T) -> bool) -> Option<(Vec<T>, Vec<T>)> {
+    let at = v.iter().position(pred)?;
+    let (left, right) = v.split_at(at);
+    Some((left.to_vec(), right.to_vec()))
+}
+
 /// Returns a new vector containing only unique elements from the input slice,
 /// preserving the order of their first occurrence.
 ///
@@ -184,7 +342,8 @@ pub fn split_at_vec<T: Clone>(v: &mut Vec<T>, at: usize) -> (Vec<T>, Vec<T>) {
 /// - The original slice is not modified by this operation.
 /// - Compared to the O(n²) version using Vec::contains, this implementation is much more efficient
 ///   for large inputs, though it requires elements to implement the Hash trait.
-pub fn get_unique<T: Clone + Eq + std::hash::Hash>(input: &[T]) -> Vec<T> {
+#[cfg(feature = "std")]
+pub fn get_unique<T: Clone + Eq + Hash>(input: &[T]) -> Vec<T> {
     let mut seen = HashSet::with_capacity(input.len());
     let mut result = Vec::with_capacity(input.len());
 
@@ -196,3 +355,612 @@ pub fn get_unique<T: Clone + Eq + std::hash::Hash>(input: &[T]) -> Vec<T> {
 
     result
 }
+
+/// Returns the elements that occur more than once in a slice.
+///
+/// This is the complement of `get_unique`: each element that appears at least
+/// twice is listed once, in the order of its first occurrence.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement `Clone`, `Eq`, and `Hash` traits.
+///
+/// # Arguments
+///
+/// * `input` - The slice to search for repeated elements.
+///
+/// # Returns
+///
+/// A new Vec<T> containing each repeated element once.
+///
+/// # Examples
+///
+/// ```
+/// let duplicates = byteutils::vec::find_duplicates(&[1, 2, 2, 3, 3, 3, 4]);
+/// assert_eq!(duplicates, vec![2, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn find_duplicates<T: Clone + Eq + Hash>(input: &[T]) -> Vec<T> {
+    let mut seen = HashSet::with_capacity(input.len());
+    let mut reported = HashSet::new();
+    let mut result = Vec::new();
+
+    for item in input {
+        if !seen.insert(item) && reported.insert(item) {
+            result.push(item.clone());
+        }
+    }
+
+    result
+}
+
+/// Summarizes a slice as runs of consecutive equal elements.
+///
+/// This function walks the slice once and returns a `(value, run_length)` pair
+/// for each maximal run of consecutive equal elements, in order.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement `PartialEq` and `Clone`.
+///
+/// # Arguments
+///
+/// * `v` - A slice of elements to summarize.
+///
+/// # Returns
+///
+/// A Vec<(T, usize)> containing each run's value and length. Empty input returns an empty Vec.
+///
+/// # Examples
+///
+/// ```
+/// let runs = byteutils::vec::run_lengths(&[1, 1, 2, 3, 3, 3]);
+/// assert_eq!(runs, vec![(1, 2), (2, 1), (3, 3)]);
+/// ```
+pub fn run_lengths<T: PartialEq + Clone>(v: &[T]) -> Vec<(T, usize)> {
+    let mut runs: Vec<(T, usize)> = Vec::new();
+    for item in v {
+        match runs.last_mut() {
+            Some((value, count)) if value == item => *count += 1,
+            _ => runs.push((item.clone(), 1)),
+        }
+    }
+    runs
+}
+
+/// Expands `(value, run_length)` pairs back into the full sequence.
+///
+/// This is the inverse of `run_lengths`: each value is repeated `run_length`
+/// times, in order. The output is pre-allocated to the total length.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements, which must implement `Clone`.
+///
+/// # Arguments
+///
+/// * `runs` - A slice of `(value, run_length)` pairs.
+///
+/// # Returns
+///
+/// A Vec<T> containing the expanded sequence.
+///
+/// # Examples
+///
+/// ```
+/// let values = byteutils::vec::from_run_lengths(&[(1, 2), (2, 1), (3, 3)]);
+/// assert_eq!(values, vec![1, 1, 2, 3, 3, 3]);
+/// ```
+pub fn from_run_lengths<T: Clone>(runs: &[(T, usize)]) -> Vec<T> {
+    let total = runs.iter().map(|(_, count)| count).sum();
+    let mut result = Vec::with_capacity(total);
+    for (value, count) in runs {
+        result.extend(core::iter::repeat(value).take(*count).cloned());
+    }
+    result
+}
+
+/// Checks whether the elements of `needle` appear in `haystack` in order.
+///
+/// Unlike a contiguous subslice search, the matched elements may be separated by
+/// other elements in `haystack`; only their relative order matters. An empty
+/// `needle` is always contained.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slices, which must implement `PartialEq`.
+///
+/// # Arguments
+///
+/// * `haystack` - The slice to search in.
+/// * `needle` - The elements to look for, in order.
+///
+/// # Returns
+///
+/// `true` if `needle` is a subsequence of `haystack`, `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// assert!(byteutils::vec::contains_subsequence(&[1, 2, 3, 4, 5], &[1, 3, 5]));
+/// assert!(!byteutils::vec::contains_subsequence(&[1, 2, 3, 4, 5], &[3, 1]));
+/// ```
+pub fn contains_subsequence<T: PartialEq>(haystack: &[T], needle: &[T]) -> bool {
+    let mut remaining = needle.iter().peekable();
+    for item in haystack {
+        if remaining.peek() == Some(&item) {
+            remaining.next();
+        }
+    }
+    remaining.peek().is_none()
+}
+
+/// Splits a slice into cloned chunks of a fixed size.
+///
+/// Every chunk contains `size` elements, except the last one, which is shorter
+/// if the length of `input` is not a multiple of `size`.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement the `Clone` trait
+///
+/// # Arguments
+///
+/// * `input` - The slice to split
+/// * `size` - The number of elements per chunk
+///
+/// # Returns
+///
+/// A Vec<Vec<T>> containing the chunks in order. Empty input returns an empty Vec.
+///
+/// # Panics
+///
+/// This function will panic if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let chunks = byteutils::vec::chunk_vec(&[1, 2, 3, 4, 5], 2);
+/// assert_eq!(chunks, vec![vec![1, 2], vec![3, 4], vec![5]]);
+/// ```
+pub fn chunk_vec<T: Clone>(input: &[T], size: usize) -> Vec<Vec<T>> {
+    if size == 0 {
+        panic!("chunk size must be greater than 0");
+    }
+    input.chunks(size).map(|chunk| chunk.to_vec()).collect()
+}
+
+/// Returns every contiguous window of a fixed size, cloned.
+///
+/// Consecutive windows overlap and start one element apart, so a slice of length
+/// `n` yields `n - size + 1` windows. This is the overlapping counterpart of
+/// `chunk_vec`. If `size` is larger than the slice, the result is empty.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement the `Clone` trait
+///
+/// # Arguments
+///
+/// * `input` - The slice to take windows from
+/// * `size` - The number of elements per window
+///
+/// # Returns
+///
+/// A Vec<Vec<T>> containing the windows in order.
+///
+/// # Panics
+///
+/// This function will panic if `size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let windows = byteutils::vec::window_vec(&[1, 2, 3, 4], 2);
+/// assert_eq!(windows, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+/// ```
+pub fn window_vec<T: Clone>(input: &[T], size: usize) -> Vec<Vec<T>> {
+    if size == 0 {
+        panic!("window size must be greater than 0");
+    }
+    input.windows(size).map(|window| window.to_vec()).collect()
+}
+
+/// Computes the simple moving average of a numeric slice.
+///
+/// Each output value is the mean of one full window, so the result has
+/// `input.len() - window + 1` elements, matching the windows returned by
+/// `window_vec` without cloning them. If `window` is larger than the slice, the
+/// result is empty.
+///
+/// # Arguments
+///
+/// * `input` - The values to average
+/// * `window` - The number of consecutive values in each average
+///
+/// # Returns
+///
+/// A Vec<f64> containing the average of each window position, in order.
+///
+/// # Panics
+///
+/// This function will panic if `window` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let averages = byteutils::vec::moving_average(&[1.0, 2.0, 3.0, 4.0], 2);
+/// assert_eq!(averages, vec![1.5, 2.5, 3.5]);
+/// ```
+pub fn moving_average(input: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 {
+        panic!("window size must be greater than 0");
+    }
+    input
+        .windows(window)
+        .map(|values| values.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+/// Splits a slice into `n` contiguous chunks of nearly equal size.
+///
+/// Unlike `chunk_vec`, which fixes the chunk size, this fixes the number of
+/// chunks. The first `len % n` chunks get one extra element, so chunk sizes
+/// differ by at most one. Exactly `n` chunks are always returned: if `n` is
+/// greater than the length of `input`, the trailing chunks are empty.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement the `Clone` trait
+///
+/// # Arguments
+///
+/// * `input` - The slice to split
+/// * `n` - The number of chunks to produce
+///
+/// # Returns
+///
+/// A Vec<Vec<T>> containing `n` chunks in order.
+///
+/// # Panics
+///
+/// This function will panic if `n` is 0.
+///
+/// # Examples
+///
+/// ```
+/// let parts = byteutils::vec::split_vec_into_n(&[1, 2, 3, 4, 5, 6, 7], 3);
+/// assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+/// ```
+pub fn split_vec_into_n<T: Clone>(input: &[T], n: usize) -> Vec<Vec<T>> {
+    if n == 0 {
+        panic!("number of chunks must be greater than 0");
+    }
+    let base = input.len() / n;
+    let extra = input.len() % n;
+
+    let mut rest = input;
+    (0..n)
+        .map(|i| {
+            let (chunk, tail) = rest.split_at(base + usize::from(i < extra));
+            rest = tail;
+            chunk.to_vec()
+        })
+        .collect()
+}
+
+/// Concatenates a slice of vectors into a single flat vector.
+///
+/// The inner vectors are appended in order, and the order of elements within each
+/// inner vector is preserved. Elements are cloned, so the input is not consumed.
+/// The result is pre-allocated to the total number of elements.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the vectors, which must implement the `Clone` trait
+///
+/// # Arguments
+///
+/// * `input` - A slice of vectors to flatten
+///
+/// # Returns
+///
+/// A Vec<T> containing every element of every inner vector.
+///
+/// # Examples
+///
+/// ```
+/// let nested = vec![vec![1, 2], vec![], vec![3]];
+/// assert_eq!(byteutils::vec::flatten_vec(&nested), vec![1, 2, 3]);
+/// ```
+pub fn flatten_vec<T: Clone>(input: &[Vec<T>]) -> Vec<T> {
+    let total = input.iter().map(Vec::len).sum();
+    let mut result = Vec::with_capacity(total);
+    for inner in input {
+        result.extend_from_slice(inner);
+    }
+    result
+}
+
+/// Pairs up the elements of two slices index by index.
+///
+/// Like `Iterator::zip`, pairing stops at the end of the shorter slice, so any
+/// extra elements of the longer slice are ignored. Elements are cloned into the
+/// returned tuples.
+///
+/// # Type Parameters
+///
+/// * `A` - The type of elements in the first slice, which must implement `Clone`.
+/// * `B` - The type of elements in the second slice, which must implement `Clone`.
+///
+/// # Arguments
+///
+/// * `a` - The slice providing the first element of each pair.
+/// * `b` - The slice providing the second element of each pair.
+///
+/// # Returns
+///
+/// A Vec<(A, B)> with one pair per index of the shorter slice.
+///
+/// # Examples
+///
+/// ```
+/// let pairs = byteutils::vec::zip_vecs(&[1, 2, 3], &["a", "b"]);
+/// assert_eq!(pairs, vec![(1, "a"), (2, "b")]);
+/// ```
+pub fn zip_vecs<A: Clone, B: Clone>(a: &[A], b: &[B]) -> Vec<(A, B)> {
+    a.iter().cloned().zip(b.iter().cloned()).collect()
+}
+
+/// Splits a slice of pairs into two vectors.
+///
+/// This is the inverse of `zip_vecs`: the first element of every pair goes into
+/// the first vector and the second element into the second, keeping their order.
+///
+/// # Type Parameters
+///
+/// * `A` - The type of the first element of each pair, which must implement `Clone`.
+/// * `B` - The type of the second element of each pair, which must implement `Clone`.
+///
+/// # Arguments
+///
+/// * `input` - The slice of pairs to split.
+///
+/// # Returns
+///
+/// A tuple of two vectors of the same length as `input`.
+///
+/// # Examples
+///
+/// ```
+/// let (numbers, letters) = byteutils::vec::unzip_vec(&[(1, 'a'), (2, 'b')]);
+/// assert_eq!(numbers, vec![1, 2]);
+/// assert_eq!(letters, vec!['a', 'b']);
+/// ```
+pub fn unzip_vec<A: Clone, B: Clone>(input: &[(A, B)]) -> (Vec<A>, Vec<B>) {
+    input.iter().cloned().unzip()
+}
+
+/// Merges two slices by alternating their elements.
+///
+/// The result is `a[0], b[0], a[1], b[1], ...`. Once the shorter slice runs out,
+/// the remaining elements of the longer one are appended in order.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slices, which must implement the `Clone` trait.
+///
+/// # Arguments
+///
+/// * `a` - The slice whose elements come first in each pair.
+/// * `b` - The slice whose elements come second in each pair.
+///
+/// # Returns
+///
+/// A Vec<T> containing every element of `a` and `b`.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(byteutils::vec::interleave(&[1, 3, 5], &[2, 4]), vec![1, 2, 3, 4, 5]);
+/// ```
+pub fn interleave<T: Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    let shared = a.len().min(b.len());
+    let mut result = Vec::with_capacity(a.len() + b.len());
+    for (x, y) in a.iter().zip(b) {
+        result.push(x.clone());
+        result.push(y.clone());
+    }
+    result.extend_from_slice(&a[shared..]);
+    result.extend_from_slice(&b[shared..]);
+    result
+}
+
+/// Groups the elements of a slice into buckets by a computed key.
+///
+/// This function calls `key_fn` on every element and collects cloned elements into
+/// a HashMap keyed by the result. Within each bucket, elements keep the order in
+/// which they appear in `input`.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement `Clone`.
+/// * `K` - The key type, which must implement `Eq` and `Hash`.
+///
+/// # Arguments
+///
+/// * `input` - The slice to group.
+/// * `key_fn` - A closure that computes the bucket key of an element.
+///
+/// # Returns
+///
+/// A HashMap from each key to the elements that produced it.
+///
+/// # Examples
+///
+/// ```
+/// let groups = byteutils::vec::group_by_key(&[1, 2, 3, 4], |x| x % 2);
+/// assert_eq!(groups[&0], vec![2, 4]);
+/// assert_eq!(groups[&1], vec![1, 3]);
+/// ```
+#[cfg(feature = "std")]
+pub fn group_by_key<T: Clone, K: Eq + Hash>(
+    input: &[T],
+    key_fn: impl Fn(&T) -> K,
+) -> HashMap<K, Vec<T>> {
+    let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+    for item in input {
+        groups.entry(key_fn(item)).or_default().push(item.clone());
+    }
+    groups
+}
+
+/// Returns the unique elements of `a` that are also present in `b`.
+///
+/// The result preserves the order of first occurrence in `a` and contains each
+/// element only once, consistent with `get_unique`.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slices, which must implement `Clone`, `Eq`, and `Hash` traits.
+///
+/// # Arguments
+///
+/// * `a` - The slice whose order is preserved.
+/// * `b` - The slice to intersect with.
+///
+/// # Returns
+///
+/// A new `Vec<T>` with the elements common to both slices.
+///
+/// # Examples
+///
+/// ```
+/// let common = byteutils::vec::intersection(&[3, 1, 2, 3, 4], &[4, 3, 5]);
+/// assert_eq!(common, vec![3, 4]);
+/// ```
+#[cfg(feature = "std")]
+pub fn intersection<T: Clone + Eq + Hash>(a: &[T], b: &[T]) -> Vec<T> {
+    let other: HashSet<&T> = b.iter().collect();
+    let mut seen = HashSet::with_capacity(a.len());
+    a.iter()
+        .filter(|item| other.contains(item) && seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+/// Returns the unique elements of `a` that are not present in `b`.
+///
+/// The result preserves the order of first occurrence in `a` and contains each
+/// element only once, consistent with `get_unique`. If `b` is empty, the result
+/// equals `get_unique(a)`.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slices, which must implement `Clone`, `Eq`, and `Hash` traits.
+///
+/// # Arguments
+///
+/// * `a` - The slice to take elements from.
+/// * `b` - The slice of elements to exclude.
+///
+/// # Returns
+///
+/// A new `Vec<T>` with the elements of `a` missing from `b`.
+///
+/// # Examples
+///
+/// ```
+/// let only_in_a = byteutils::vec::difference(&[3, 1, 2, 3, 4], &[4, 5]);
+/// assert_eq!(only_in_a, vec![3, 1, 2]);
+/// ```
+#[cfg(feature = "std")]
+pub fn difference<T: Clone + Eq + Hash>(a: &[T], b: &[T]) -> Vec<T> {
+    let other: HashSet<&T> = b.iter().collect();
+    let mut seen = HashSet::with_capacity(a.len());
+    a.iter()
+        .filter(|item| !other.contains(item) && seen.insert(*item))
+        .cloned()
+        .collect()
+}
+
+/// Counts how many times each distinct element appears in a slice.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement `Clone`, `Eq`, and `Hash` traits.
+///
+/// # Arguments
+///
+/// * `input` - The slice to count.
+///
+/// # Returns
+///
+/// A HashMap from each distinct element to its number of occurrences. An empty
+/// slice returns an empty map.
+///
+/// # Examples
+///
+/// ```
+/// let counts = byteutils::vec::count_occurrences(&["a", "b", "a"]);
+/// assert_eq!(counts["a"], 2);
+/// assert_eq!(counts["b"], 1);
+/// ```
+#[cfg(feature = "std")]
+pub fn count_occurrences<T: Clone + Eq + Hash>(input: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for item in input {
+        *counts.entry(item.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the `n` most frequent elements of a slice with their counts.
+///
+/// Elements are sorted by count in descending order. Elements with the same count
+/// keep the order of their first occurrence in `input`, so the result is
+/// deterministic. If `n` is larger than the number of distinct elements, all of
+/// them are returned.
+///
+/// # Type Parameters
+///
+/// * `T` - The type of elements in the slice, which must implement `Clone`, `Eq`, and `Hash` traits.
+///
+/// # Arguments
+///
+/// * `input` - The slice to analyze.
+/// * `n` - The maximum number of elements to return.
+///
+/// # Returns
+///
+/// A `Vec<(T, usize)>` of at most `n` elements paired with their counts.
+///
+/// # Examples
+///
+/// ```
+/// let top = byteutils::vec::most_common(&[1, 1, 2, 3, 3, 3], 2);
+/// assert_eq!(top, vec![(3, 3), (1, 2)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn most_common<T: Clone + Eq + Hash>(input: &[T], n: usize) -> Vec<(T, usize)> {
+    // Maps each distinct element to its index in `counts`, which is in first-occurrence order.
+    let mut index: HashMap<&T, usize> = HashMap::new();
+    let mut counts: Vec<(&T, usize)> = Vec::new();
+    for item in input {
+        match index.get(item) {
+            Some(&i) => counts[i].1 += 1,
+            None => {
+                index.insert(item, counts.len());
+                counts.push((item, 1));
+            }
+        }
+    }
+
+    // A stable sort keeps first-occurrence order among equal counts.
+    counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    counts
+        .into_iter()
+        .take(n)
+        .map(|(item, count)| (item.clone(), count))
+        .collect()
+}